@@ -22,15 +22,20 @@ impl CharacterDelimitedDecoderConfig {
         }
     }
     /// Build the `CharacterDelimitedDecoder` from this configuration.
-    pub const fn build(&self) -> CharacterDelimitedDecoder {
-        if let Some(max_length) = self.character_delimited.max_length {
-            CharacterDelimitedDecoder::new_with_max_length(
-                self.character_delimited.delimiter,
-                max_length,
-            )
+    pub fn build(&self) -> CharacterDelimitedDecoder {
+        let options = &self.character_delimited;
+        let mut decoder = if options.additional_delimiters.is_empty() {
+            CharacterDelimitedDecoder::new(options.delimiter)
         } else {
-            CharacterDelimitedDecoder::new(self.character_delimited.delimiter)
+            CharacterDelimitedDecoder::with_delimiter_set(
+                options.delimiter,
+                &options.additional_delimiters,
+            )
+        };
+        if let Some(max_length) = options.max_length {
+            decoder.max_length = max_length;
         }
+        decoder
     }
 }
 
@@ -56,6 +61,15 @@ pub struct CharacterDelimitedDecoderOptions {
     /// ensures that processing is not actually unbounded.
     #[serde(skip_serializing_if = "vector_core::serde::is_default")]
     pub max_length: Option<usize>,
+
+    /// Additional bytes that also delimit byte sequences.
+    ///
+    /// When set, any of `delimiter` and these bytes ends a frame. If the newline byte (`10`) is
+    /// one of the delimiters, a carriage return (`13`) directly before it is stripped, so that
+    /// frames ending in CRLF and LF can be mixed.
+    #[configurable(metadata(docs::examples = 10))]
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    pub additional_delimiters: Vec<u8>,
 }
 
 impl CharacterDelimitedDecoderOptions {
@@ -64,6 +78,7 @@ impl CharacterDelimitedDecoderOptions {
         Self {
             delimiter,
             max_length,
+            additional_delimiters: Vec::new(),
         }
     }
 }
@@ -75,6 +90,26 @@ pub struct CharacterDelimitedDecoder {
     pub delimiter: u8,
     /// The maximum length of the byte buffer.
     pub max_length: usize,
+    delimiters: Delimiters,
+}
+
+/// The bytes, besides `delimiter`, that end a frame.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+enum Delimiters {
+    /// Only `delimiter` ends a frame.
+    Single,
+    /// Any byte in the set ends a frame, with one bit per byte value.
+    Set([u64; 4]),
+}
+
+impl Delimiters {
+    fn set(bytes: &[u8]) -> Self {
+        let mut set = [0; 4];
+        for &byte in bytes {
+            set[usize::from(byte / 64)] |= 1 << (byte % 64);
+        }
+        Delimiters::Set(set)
+    }
 }
 
 impl CharacterDelimitedDecoder {
@@ -83,6 +118,30 @@ impl CharacterDelimitedDecoder {
         CharacterDelimitedDecoder {
             delimiter,
             max_length: usize::MAX,
+            delimiters: Delimiters::Single,
+        }
+    }
+
+    /// Creates a `CharacterDelimitedDecoder` where any byte in `delimiters` ends a frame.
+    ///
+    /// If `\n` is one of the delimiters, a `\r` directly before it is treated as part of the
+    /// delimiter, so that `\r\n` and `\n` terminated frames can be mixed. The first byte is
+    /// used as `delimiter`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `delimiters` is empty.
+    pub fn new_with_delims(delimiters: &[u8]) -> vector_common::Result<Self> {
+        let (&delimiter, additional) = delimiters
+            .split_first()
+            .ok_or("at least one delimiter is required")?;
+        Ok(Self::with_delimiter_set(delimiter, additional))
+    }
+
+    fn with_delimiter_set(delimiter: u8, additional: &[u8]) -> Self {
+        CharacterDelimitedDecoder {
+            delimiters: Delimiters::set(additional),
+            ..CharacterDelimitedDecoder::new(delimiter)
         }
     }
 
@@ -100,13 +159,46 @@ impl CharacterDelimitedDecoder {
     pub const fn max_length(&self) -> usize {
         self.max_length
     }
-}
 
-impl Decoder for CharacterDelimitedDecoder {
-    type Item = Bytes;
-    type Error = BoxedFramingError;
+    fn is_delimiter(&self, byte: u8) -> bool {
+        match self.delimiters {
+            Delimiters::Single => byte == self.delimiter,
+            Delimiters::Set(set) => {
+                byte == self.delimiter || set[usize::from(byte / 64)] & (1 << (byte % 64)) != 0
+            }
+        }
+    }
 
-    fn decode(&mut self, buf: &mut BytesMut) -> Result<Option<Bytes>, Self::Error> {
+    /// Finds the next delimiter in `buf`, returning the length of the frame before it and the
+    /// length of the delimiter itself.
+    fn find_delimiter(&self, buf: &[u8], eof: bool) -> Option<(usize, usize)> {
+        if self.delimiters == Delimiters::Single {
+            return memchr(self.delimiter, buf).map(|idx| (idx, 1));
+        }
+
+        let idx = buf.iter().position(|&byte| self.is_delimiter(byte))?;
+        if !self.is_delimiter(b'\n') {
+            return Some((idx, 1));
+        }
+
+        match buf[idx] {
+            b'\r' => match buf.get(idx + 1) {
+                Some(b'\n') => Some((idx, 2)),
+                Some(_) => Some((idx, 1)),
+                None if eof => Some((idx, 1)),
+                // This may be the start of a `\r\n` that hasn't been fully received yet.
+                None => None,
+            },
+            b'\n' if idx > 0 && buf[idx - 1] == b'\r' => Some((idx - 1, 2)),
+            _ => Some((idx, 1)),
+        }
+    }
+
+    fn decode_frame(
+        &mut self,
+        buf: &mut BytesMut,
+        eof: bool,
+    ) -> Result<Option<Bytes>, BoxedFramingError> {
         loop {
             // This function has the following goal: we are searching for
             // sub-buffers delimited by `self.delimiter` with size no more than
@@ -114,9 +206,9 @@ impl Decoder for CharacterDelimitedDecoder {
             // `self.max_length` we discard it, else we return it. At the end of
             // the buffer if the delimiter is not present the remainder of the
             // buffer is discarded.
-            match memchr(self.delimiter, buf) {
+            match self.find_delimiter(buf, eof) {
                 None => return Ok(None),
-                Some(next_delimiter_idx) => {
+                Some((next_delimiter_idx, delimiter_len)) => {
                     if next_delimiter_idx > self.max_length {
                         // The discovered sub-buffer is too big, so we discard
                         // it, taking care to also discard the delimiter.
@@ -126,23 +218,32 @@ impl Decoder for CharacterDelimitedDecoder {
                             max_length = self.max_length,
                             internal_log_rate_limit = true
                         );
-                        buf.advance(next_delimiter_idx + 1);
+                        buf.advance(next_delimiter_idx + delimiter_len);
                     } else {
                         let frame = buf.split_to(next_delimiter_idx).freeze();
                         trace!(
                             message = "Decoding the frame.",
                             bytes_processed = frame.len()
                         );
-                        buf.advance(1); // scoot past the delimiter
+                        buf.advance(delimiter_len); // scoot past the delimiter
                         return Ok(Some(frame));
                     }
                 }
             }
         }
     }
+}
+
+impl Decoder for CharacterDelimitedDecoder {
+    type Item = Bytes;
+    type Error = BoxedFramingError;
+
+    fn decode(&mut self, buf: &mut BytesMut) -> Result<Option<Bytes>, Self::Error> {
+        self.decode_frame(buf, false)
+    }
 
     fn decode_eof(&mut self, buf: &mut BytesMut) -> Result<Option<Bytes>, Self::Error> {
-        match self.decode(buf)? {
+        match self.decode_frame(buf, true)? {
             Some(frame) => Ok(Some(frame)),
             None => {
                 if buf.is_empty() {
@@ -204,6 +305,88 @@ mod tests {
         assert_eq!(codec.decode_eof(buf).unwrap(), None);
     }
 
+    #[test]
+    fn decode_mixed_line_endings() {
+        let mut codec = CharacterDelimitedDecoder::new_with_delims(b"\n").unwrap();
+        let buf = &mut BytesMut::new();
+        buf.put_slice(b"abc\r\ndef\nghi\r\n\r\njkl");
+
+        assert_eq!(codec.decode(buf).unwrap(), Some(Bytes::from("abc")));
+        assert_eq!(codec.decode(buf).unwrap(), Some(Bytes::from("def")));
+        assert_eq!(codec.decode(buf).unwrap(), Some(Bytes::from("ghi")));
+        assert_eq!(codec.decode(buf).unwrap(), Some(Bytes::from("")));
+        assert_eq!(codec.decode(buf).unwrap(), None);
+        assert_eq!(codec.decode_eof(buf).unwrap(), Some(Bytes::from("jkl")));
+        assert_eq!(codec.decode_eof(buf).unwrap(), None);
+    }
+
+    #[test]
+    fn decode_delimiter_set() {
+        let mut codec = CharacterDelimitedDecoder::new_with_delims(b"\r\n").unwrap();
+        let buf = &mut BytesMut::new();
+        buf.put_slice(b"abc\r\ndef\rghi\njkl\r");
+
+        assert_eq!(codec.decode(buf).unwrap(), Some(Bytes::from("abc")));
+        assert_eq!(codec.decode(buf).unwrap(), Some(Bytes::from("def")));
+        assert_eq!(codec.decode(buf).unwrap(), Some(Bytes::from("ghi")));
+        // The trailing `\r` could still be followed by a `\n`.
+        assert_eq!(codec.decode(buf).unwrap(), None);
+        buf.put_slice(b"\nmno");
+        assert_eq!(codec.decode(buf).unwrap(), Some(Bytes::from("jkl")));
+        buf.put_slice(b"\r");
+        assert_eq!(codec.decode_eof(buf).unwrap(), Some(Bytes::from("mno")));
+        assert_eq!(codec.decode_eof(buf).unwrap(), None);
+    }
+
+    #[test]
+    fn decode_delimiter_set_without_newline() {
+        let mut codec = CharacterDelimitedDecoder::new_with_delims(b",;").unwrap();
+        let buf = &mut BytesMut::new();
+        buf.put_slice(b"a,b;\r\n,");
+
+        assert_eq!(codec.decode(buf).unwrap(), Some(Bytes::from("a")));
+        assert_eq!(codec.decode(buf).unwrap(), Some(Bytes::from("b")));
+        assert_eq!(codec.decode(buf).unwrap(), Some(Bytes::from("\r\n")));
+        assert_eq!(codec.decode(buf).unwrap(), None);
+    }
+
+    #[test]
+    fn decode_delimiter_set_max_length() {
+        const MAX_LENGTH: usize = 3;
+
+        let mut codec = CharacterDelimitedDecoder::new_with_delims(b"\n").unwrap();
+        codec.max_length = MAX_LENGTH;
+        let buf = &mut BytesMut::new();
+        buf.put_slice(b"abcd\r\nabc\r\nab\n");
+
+        assert_eq!(codec.decode(buf).unwrap(), Some(Bytes::from("abc")));
+        assert_eq!(codec.decode(buf).unwrap(), Some(Bytes::from("ab")));
+        assert_eq!(codec.decode(buf).unwrap(), None);
+    }
+
+    #[test]
+    fn new_with_delims_empty() {
+        assert!(CharacterDelimitedDecoder::new_with_delims(b"").is_err());
+    }
+
+    #[test]
+    fn build_with_additional_delimiters() {
+        let config = CharacterDelimitedDecoderConfig {
+            character_delimited: CharacterDelimitedDecoderOptions {
+                additional_delimiters: vec![b'\n'],
+                ..CharacterDelimitedDecoderOptions::new(b';', Some(3))
+            },
+        };
+        let mut codec = config.build();
+        let buf = &mut BytesMut::new();
+        buf.put_slice(b"abc;def\r\nabcd\nab");
+
+        assert_eq!(codec.decode(buf).unwrap(), Some(Bytes::from("abc")));
+        assert_eq!(codec.decode(buf).unwrap(), Some(Bytes::from("def")));
+        assert_eq!(codec.decode(buf).unwrap(), None);
+        assert_eq!(codec.decode_eof(buf).unwrap(), Some(Bytes::from("ab")));
+    }
+
     // Regression test for [infinite loop bug](https://github.com/vectordotdev/vector/issues/2564)
     // Derived from https://github.com/tokio-rs/tokio/issues/1483
     #[test]
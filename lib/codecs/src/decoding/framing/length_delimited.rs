@@ -118,6 +118,33 @@ mod tests {
         assert_eq!(decoder.decode(&mut input).unwrap(), None);
     }
 
+    #[test]
+    fn decode_frame_split_across_reads() {
+        let mut input = BytesMut::new();
+        let mut decoder = LengthDelimitedDecoder::default();
+
+        input.extend_from_slice(b"\x00\x00");
+        assert_eq!(decoder.decode(&mut input).unwrap(), None);
+
+        input.extend_from_slice(b"\x00\x06foo");
+        assert_eq!(decoder.decode(&mut input).unwrap(), None);
+
+        input.extend_from_slice(b"bar");
+        assert_eq!(decoder.decode(&mut input).unwrap().unwrap(), "foobar");
+        assert_eq!(decoder.decode(&mut input).unwrap(), None);
+    }
+
+    #[test]
+    fn decode_frame_exceeding_max_frame_length() {
+        let mut input = BytesMut::from("\x00\x00\x00\x04fooo");
+        let mut decoder = LengthDelimitedDecoder::new(&LengthDelimitedCoderOptions {
+            max_frame_length: 3,
+            ..Default::default()
+        });
+
+        assert!(decoder.decode(&mut input).is_err());
+    }
+
     #[test]
     fn decode_frame_ignore_exceeding_bytes_without_header() {
         let mut input = BytesMut::from("\x00\x00\x00\x03fooo");
@@ -23,7 +23,8 @@ pub use newline_delimited::{
     NewlineDelimitedDecoder, NewlineDelimitedDecoderConfig, NewlineDelimitedDecoderOptions,
 };
 pub use octet_counting::{
-    OctetCountingDecoder, OctetCountingDecoderConfig, OctetCountingDecoderOptions,
+    OctetCountingDecoder, OctetCountingDecoderConfig, OctetCountingDecoderError,
+    OctetCountingDecoderOptions,
 };
 use tokio_util::codec::LinesCodecError;
 
@@ -1,12 +1,14 @@
-use std::io;
+use std::{any::Any, io};
 
 use bytes::{Buf, Bytes, BytesMut};
 use derivative::Derivative;
-use tokio_util::codec::{LinesCodec, LinesCodecError};
+use snafu::Snafu;
+use tokio_util::codec::{Decoder, LinesCodec, LinesCodecError};
 use tracing::trace;
 use vector_config::configurable_component;
 
-use super::BoxedFramingError;
+use super::{BoxedFramingError, FramingError};
+use crate::StreamDecodingError;
 
 /// Config used to build a `OctetCountingDecoder`.
 #[configurable_component]
@@ -38,6 +40,34 @@ pub struct OctetCountingDecoderOptions {
     pub max_length: Option<usize>,
 }
 
+/// Errors that can occur while decoding octet counted frames.
+#[derive(Clone, Debug, Snafu, PartialEq, Eq)]
+pub enum OctetCountingDecoderError {
+    /// The declared length of a frame is greater than the maximum frame length. The frame is
+    /// discarded.
+    #[snafu(display(
+        "Frame length limit exceeded: declared length {declared_len} is greater than the maximum of {max_length}"
+    ))]
+    FrameTooLong {
+        /// The length declared by the frame's octet count.
+        declared_len: usize,
+        /// The maximum frame length of the decoder.
+        max_length: usize,
+    },
+}
+
+impl StreamDecodingError for OctetCountingDecoderError {
+    fn can_continue(&self) -> bool {
+        true
+    }
+}
+
+impl FramingError for OctetCountingDecoderError {
+    fn as_any(&self) -> &dyn Any {
+        self as &dyn Any
+    }
+}
+
 /// Codec using the `Octet Counting` format as specified in
 /// <https://tools.ietf.org/html/rfc6587#section-3.4.1>.
 #[derive(Clone, Debug)]
@@ -75,7 +105,7 @@ impl OctetCountingDecoder {
         &mut self,
         state: State,
         src: &mut BytesMut,
-    ) -> Result<Option<Bytes>, LinesCodecError> {
+    ) -> Result<Option<Bytes>, BoxedFramingError> {
         // Encoding scheme:
         //
         // len ' ' data
@@ -94,13 +124,11 @@ impl OctetCountingDecoder {
             (State::Discarding(chars), _, _) if src.len() >= chars => {
                 // We have a certain number of chars to discard.
                 //
-                // There are enough chars in this frame to discard
+                // There are enough chars in this frame to discard. The frame
+                // was already reported as too long when its length was read.
                 src.advance(chars);
                 self.octet_decoding = None;
-                Err(LinesCodecError::Io(io::Error::new(
-                    io::ErrorKind::Other,
-                    "Frame length limit exceeded",
-                )))
+                Ok(None)
             }
 
             (State::Discarding(chars), _, _) => {
@@ -108,7 +136,7 @@ impl OctetCountingDecoder {
                 //
                 // There aren't enough in this frame so we need to discard the
                 // entire frame and adjust the amount to discard accordingly.
-                self.octet_decoding = Some(State::Discarding(src.len() - chars));
+                self.octet_decoding = Some(State::Discarding(chars - src.len()));
                 src.advance(src.len());
                 Ok(None)
             }
@@ -120,7 +148,8 @@ impl OctetCountingDecoder {
                 Err(LinesCodecError::Io(io::Error::new(
                     io::ErrorKind::Other,
                     "Frame length limit exceeded",
-                )))
+                ))
+                .into())
             }
 
             (State::DiscardingToEol, None, _) => {
@@ -141,6 +170,12 @@ impl OctetCountingDecoder {
                 // will hopefully give us a sensible length for our message.
                 let len: usize = match std::str::from_utf8(&src[..space_pos])
                     .map_err(|_| ())
+                    // RFC 6587 octet counts have no leading zeros, so only a
+                    // lone `0` may start with one.
+                    .and_then(|num| match num.strip_prefix('0') {
+                        Some(rest) if !rest.is_empty() => Err(()),
+                        _ => Ok(num),
+                    })
                     .and_then(|num| num.parse().map_err(|_| ()))
                 {
                     Ok(len) => len,
@@ -154,7 +189,8 @@ impl OctetCountingDecoder {
                         return Err(LinesCodecError::Io(io::Error::new(
                             io::ErrorKind::InvalidData,
                             "Unable to decode message len as number",
-                        )));
+                        ))
+                        .into());
                     }
                 };
 
@@ -168,7 +204,11 @@ impl OctetCountingDecoder {
                     self.octet_decoding = Some(State::Discarding(len));
                     src.advance(space_pos + 1);
 
-                    Ok(None)
+                    Err(OctetCountingDecoderError::FrameTooLong {
+                        declared_len: len,
+                        max_length: self.other.max_length(),
+                    }
+                    .into())
                 } else if let Some(msg) = src.get(from..to) {
                     let bytes = match std::str::from_utf8(msg) {
                         Ok(_) => Bytes::copy_from_slice(msg),
@@ -182,7 +222,8 @@ impl OctetCountingDecoder {
                             return Err(LinesCodecError::Io(io::Error::new(
                                 io::ErrorKind::InvalidData,
                                 "Unable to decode message as UTF8",
-                            )));
+                            ))
+                            .into());
                         }
                     };
 
@@ -206,7 +247,8 @@ impl OctetCountingDecoder {
                 Err(LinesCodecError::Io(io::Error::new(
                     io::ErrorKind::Other,
                     "Frame length limit exceeded",
-                )))
+                ))
+                .into())
             }
 
             (State::NotDiscarding, None, _) if src.len() < self.other.max_length() => {
@@ -234,11 +276,12 @@ impl OctetCountingDecoder {
     fn checked_decode(
         &mut self,
         src: &mut BytesMut,
-    ) -> Option<Result<Option<Bytes>, LinesCodecError>> {
+    ) -> Option<Result<Option<Bytes>, BoxedFramingError>> {
         if let Some(&first_byte) = src.first() {
-            if (49..=57).contains(&first_byte) {
-                // First character is non zero number so we can assume that
-                // octet count framing is used.
+            if first_byte.is_ascii_digit() {
+                // First character is a number so we can assume that octet
+                // count framing is used. A zero length is degenerate but
+                // valid.
                 trace!("Octet counting encoded event detected.");
                 self.octet_decoding = Some(State::NotDiscarding);
             }
@@ -247,6 +290,31 @@ impl OctetCountingDecoder {
         self.octet_decoding
             .map(|state| self.octet_decode(state, src))
     }
+
+    fn decode_frame(
+        &mut self,
+        src: &mut BytesMut,
+        eof: bool,
+    ) -> Result<Option<Bytes>, BoxedFramingError> {
+        loop {
+            let discarding = matches!(self.octet_decoding, Some(State::Discarding(_)));
+            let frame = if let Some(ret) = self.checked_decode(src) {
+                ret?
+            } else if eof {
+                // Octet counting isn't used so fallback to newline codec.
+                self.other.decode_eof(src)?.map(Into::into)
+            } else {
+                self.other.decode(src)?.map(Into::into)
+            };
+
+            // Once an oversized frame has been skipped, carry on with whatever
+            // follows it rather than waiting for more data.
+            if frame.is_none() && discarding && self.octet_decoding.is_none() {
+                continue;
+            }
+            return Ok(frame);
+        }
+    }
 }
 
 impl Default for OctetCountingDecoder {
@@ -260,27 +328,11 @@ impl tokio_util::codec::Decoder for OctetCountingDecoder {
     type Error = BoxedFramingError;
 
     fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
-        if let Some(ret) = self.checked_decode(src) {
-            ret
-        } else {
-            // Octet counting isn't used so fallback to newline codec.
-            self.other
-                .decode(src)
-                .map(|line| line.map(|line| line.into()))
-        }
-        .map_err(Into::into)
+        self.decode_frame(src, false)
     }
 
     fn decode_eof(&mut self, buf: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
-        if let Some(ret) = self.checked_decode(buf) {
-            ret
-        } else {
-            // Octet counting isn't used so fallback to newline codec.
-            self.other
-                .decode_eof(buf)
-                .map(|line| line.map(|line| line.into()))
-        }
-        .map_err(Into::into)
+        self.decode_frame(buf, true)
     }
 }
 
@@ -293,6 +345,16 @@ mod tests {
 
     use super::*;
 
+    fn frame_too_long(
+        result: Result<Option<Bytes>, BoxedFramingError>,
+    ) -> Option<OctetCountingDecoderError> {
+        result
+            .err()?
+            .as_any()
+            .downcast_ref::<OctetCountingDecoderError>()
+            .cloned()
+    }
+
     #[test]
     fn non_octet_decode_works_with_multiple_frames() {
         let mut decoder = OctetCountingDecoder::new_with_max_length(128);
@@ -342,6 +404,28 @@ mod tests {
         assert_eq!(b"zork"[..], buffer);
     }
 
+    #[test]
+    fn octet_decode_zero_length_frame() {
+        let mut decoder = OctetCountingDecoder::new_with_max_length(16);
+        let mut buffer = BytesMut::from("0 5 hello");
+
+        let result = decoder.decode(&mut buffer);
+        assert_eq!(result.unwrap(), Some(Bytes::new()));
+
+        let result = decoder.decode(&mut buffer);
+        assert_eq!(result.unwrap(), Some(Bytes::from("hello")));
+    }
+
+    #[test]
+    fn octet_decode_rejects_leading_zero_length() {
+        let mut decoder = OctetCountingDecoder::new_with_max_length(16);
+        let mut buffer = BytesMut::from("07 message");
+
+        let result = decoder.decode(&mut buffer);
+        assert!(result.is_err());
+        assert_eq!(buffer, "message");
+    }
+
     #[test]
     fn octet_decode_moves_past_invalid_utf8() {
         let mut decoder = OctetCountingDecoder::new_with_max_length(16);
@@ -374,10 +458,16 @@ mod tests {
 
         buffer.put(&b"26 abcdefghijklmnopqrstuvwxyzand here we are"[..]);
         let result = decoder.decode(&mut buffer);
-        assert_eq!(Ok(None), result.map_err(|_| false));
+        assert_eq!(
+            frame_too_long(result),
+            Some(OctetCountingDecoderError::FrameTooLong {
+                declared_len: 26,
+                max_length: 16,
+            })
+        );
         let result = decoder.decode(&mut buffer);
 
-        assert!(result.is_err());
+        assert_eq!(Ok(None), result.map_err(|_| false));
         assert_eq!(b"and here we are"[..], buffer);
     }
 
@@ -387,13 +477,14 @@ mod tests {
         let mut buffer = BytesMut::with_capacity(32);
 
         buffer.put(&b"26 abc"[..]);
-        let _result = decoder.decode(&mut buffer);
+        let result = decoder.decode(&mut buffer);
+        assert!(frame_too_long(result).is_some());
 
         buffer.put(&b"defghijklmnopqrstuvwxyzand here we are"[..]);
         let result = decoder.decode(&mut buffer);
 
         println!("{:?}", result);
-        assert!(result.is_err());
+        assert_eq!(Ok(None), result.map_err(|_| false));
         assert_eq!(b"and here we are"[..], buffer);
     }
 
@@ -412,4 +503,44 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(b"32 something valid"[..], buffer);
     }
+
+    #[test]
+    fn octet_decode_reports_frame_too_long() {
+        let mut decoder = OctetCountingDecoder::new_with_max_length(16);
+        let mut buffer = BytesMut::with_capacity(32);
+
+        buffer.put(&b"99999 <13>Mar 25 21:47:46 host app: partial"[..]);
+        let result = decoder.decode(&mut buffer);
+        assert_eq!(
+            frame_too_long(result),
+            Some(OctetCountingDecoderError::FrameTooLong {
+                declared_len: 99999,
+                max_length: 16,
+            })
+        );
+
+        // The rest of the frame is discarded as it arrives, without further errors.
+        let result = decoder.decode(&mut buffer);
+        assert_eq!(Ok(None), result.map_err(|_| false));
+        assert_eq!(b""[..], buffer);
+        assert_eq!(decoder.octet_decoding, Some(State::Discarding(99999 - 37)));
+
+        buffer.put(&b"more of the same frame"[..]);
+        let result = decoder.decode(&mut buffer);
+        assert_eq!(Ok(None), result.map_err(|_| false));
+        assert_eq!(b""[..], buffer);
+    }
+
+    #[test]
+    fn octet_decode_continues_after_discarded_frame() {
+        let mut decoder = OctetCountingDecoder::new_with_max_length(16);
+        let mut buffer = BytesMut::with_capacity(32);
+
+        buffer.put(&b"20 abcdefghijklmnopqrst5 hello"[..]);
+        let result = decoder.decode(&mut buffer);
+        assert!(frame_too_long(result).is_some());
+
+        let result = decoder.decode(&mut buffer);
+        assert_eq!(Ok(Some("hello".into())), result.map_err(|_| false));
+    }
 }
@@ -24,7 +24,7 @@ pub use framing::{
     ChunkedGelfDecoderConfig, ChunkedGelfDecoderOptions, FramingError, LengthDelimitedDecoder,
     LengthDelimitedDecoderConfig, NewlineDelimitedDecoder, NewlineDelimitedDecoderConfig,
     NewlineDelimitedDecoderOptions, OctetCountingDecoder, OctetCountingDecoderConfig,
-    OctetCountingDecoderOptions,
+    OctetCountingDecoderError, OctetCountingDecoderOptions,
 };
 use smallvec::SmallVec;
 use std::fmt::Debug;
@@ -441,6 +441,7 @@ impl DeserializerConfig {
                         CharacterDelimitedDecoderOptions {
                             delimiter: b',',
                             max_length: Some(usize::MAX),
+                            ..
                         },
                 }),
             ) => "application/json",
@@ -528,6 +529,7 @@ mod tests {
                 character_delimited: CharacterDelimitedDecoderOptions {
                     delimiter: 0,
                     max_length: None,
+                    ..
                 }
             })
         ));
@@ -7,7 +7,7 @@ use snafu::ResultExt;
 
 mod line;
 
-pub use line::ErrorKind;
+pub use line::{ErrorKind, Exemplar};
 use line::{Line, Metric, MetricKind};
 
 pub const METRIC_NAME_LABEL: &str = "__name__";
@@ -36,8 +36,10 @@ pub mod proto {
 
 #[derive(Debug, snafu::Snafu, PartialEq)]
 pub enum ParserError {
-    #[snafu(display("{}, line: `{}`", kind, line))]
+    #[snafu(display("line {}: {}, `{}`", line_number, kind, line))]
     WithLine {
+        /// 1-based position of the line in the input.
+        line_number: usize,
         line: String,
         #[snafu(source)]
         kind: ErrorKind,
@@ -56,6 +58,12 @@ pub enum ParserError {
     #[snafu(display("expected value in range [0, {}], found: {}", max, value))]
     ValueOutOfRange { value: f64, max: u64 },
 
+    #[snafu(display("duplicate sample for metric `{}` with labels {:?}", name, labels))]
+    DuplicateSample {
+        name: String,
+        labels: BTreeMap<String, String>,
+    },
+
     #[snafu(display("multiple metric kinds given for metric name `{}`", name))]
     MultipleMetricKinds { name: String },
     #[snafu(display("request is missing metric name label"))]
@@ -87,6 +95,7 @@ pub struct SummaryMetric {
 pub struct HistogramBucket {
     pub bucket: f64,
     pub count: u64,
+    pub exemplar: Option<Exemplar>,
 }
 
 #[derive(Debug, Default, PartialEq)]
@@ -96,17 +105,83 @@ pub struct HistogramMetric {
     pub count: u64,
 }
 
+/// A run of consecutive buckets in a native histogram, starting `offset` buckets after the end
+/// of the previous span (or after bucket index zero for the first span).
+#[derive(Debug, Default, PartialEq)]
+pub struct BucketSpan {
+    pub offset: i32,
+    pub length: u32,
+}
+
+/// A native (sparse) histogram, as sent over remote write. Integer histograms carry their bucket
+/// counts in `*_deltas`, each relative to the previous bucket, while float histograms carry
+/// absolute counts in `*_counts`.
+#[derive(Debug, Default, PartialEq)]
+pub struct NativeHistogramMetric {
+    pub schema: i32,
+    pub zero_threshold: f64,
+    pub zero_count: f64,
+    pub count: f64,
+    pub sum: f64,
+    pub positive_spans: Vec<BucketSpan>,
+    pub positive_deltas: Vec<i64>,
+    pub positive_counts: Vec<f64>,
+    pub negative_spans: Vec<BucketSpan>,
+    pub negative_deltas: Vec<i64>,
+    pub negative_counts: Vec<f64>,
+}
+
+impl From<proto::Histogram> for NativeHistogramMetric {
+    fn from(histogram: proto::Histogram) -> Self {
+        use proto::histogram::{Count, ZeroCount};
+
+        let spans = |spans: Vec<proto::BucketSpan>| {
+            spans
+                .into_iter()
+                .map(|span| BucketSpan {
+                    offset: span.offset,
+                    length: span.length,
+                })
+                .collect()
+        };
+
+        Self {
+            schema: histogram.schema,
+            zero_threshold: histogram.zero_threshold,
+            zero_count: match histogram.zero_count {
+                Some(ZeroCount::ZeroCountInt(count)) => count as f64,
+                Some(ZeroCount::ZeroCountFloat(count)) => count,
+                None => 0.0,
+            },
+            count: match histogram.count {
+                Some(Count::CountInt(count)) => count as f64,
+                Some(Count::CountFloat(count)) => count,
+                None => 0.0,
+            },
+            sum: histogram.sum,
+            positive_spans: spans(histogram.positive_spans),
+            positive_deltas: histogram.positive_deltas,
+            positive_counts: histogram.positive_counts,
+            negative_spans: spans(histogram.negative_spans),
+            negative_deltas: histogram.negative_deltas,
+            negative_counts: histogram.negative_counts,
+        }
+    }
+}
+
 #[derive(Debug, Default, PartialEq)]
 pub struct SimpleMetric {
     pub value: f64,
+    pub exemplar: Option<Exemplar>,
 }
 
 type MetricMap<T> = IndexMap<GroupKey, T>;
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum GroupKind {
     Summary(MetricMap<SummaryMetric>),
     Histogram(MetricMap<HistogramMetric>),
+    NativeHistogram(MetricMap<NativeHistogramMetric>),
     Gauge(MetricMap<SimpleMetric>),
     Counter(MetricMap<SimpleMetric>),
     Untyped(MetricMap<SimpleMetric>),
@@ -123,9 +198,9 @@ impl GroupKind {
         }
     }
 
-    fn new_untyped(key: GroupKey, value: f64) -> Self {
+    fn new_untyped(key: GroupKey, metric: SimpleMetric) -> Self {
         let mut metrics = IndexMap::default();
-        metrics.insert(key, SimpleMetric { value });
+        metrics.insert(key, metric);
         Self::Untyped(metrics)
     }
 
@@ -133,19 +208,34 @@ impl GroupKind {
         match self {
             Self::Counter { .. } => kind == MetricKind::Counter,
             Self::Gauge { .. } => kind == MetricKind::Gauge,
-            Self::Histogram { .. } => kind == MetricKind::Histogram,
+            Self::Histogram { .. } | Self::NativeHistogram { .. } => kind == MetricKind::Histogram,
             Self::Summary { .. } => kind == MetricKind::Summary,
             Self::Untyped { .. } => true,
         }
     }
 
+    fn is_empty(&self) -> bool {
+        match self {
+            Self::Counter(metrics) | Self::Gauge(metrics) | Self::Untyped(metrics) => {
+                metrics.is_empty()
+            }
+            Self::Histogram(metrics) => metrics.is_empty(),
+            Self::NativeHistogram(metrics) => metrics.is_empty(),
+            Self::Summary(metrics) => metrics.is_empty(),
+        }
+    }
+
     /// Err(_) if there are irrecoverable error.
     /// Ok(Some(metric)) if this metric belongs to another group.
     /// Ok(None) pushed successfully.
+    ///
+    /// With `strict`, a counter, gauge, or untyped sample that repeats an existing labels and
+    /// timestamp combination is an error instead of replacing the earlier value.
     fn try_push(
         &mut self,
         prefix_len: usize,
         metric: Metric,
+        strict: bool,
     ) -> Result<Option<Metric>, ParserError> {
         let suffix = &metric.name[prefix_len..];
         let mut key = GroupKey {
@@ -153,6 +243,7 @@ impl GroupKind {
             labels: metric.labels,
         };
         let value = metric.value;
+        let exemplar = metric.exemplar;
 
         match self {
             Self::Counter(ref mut metrics)
@@ -164,9 +255,16 @@ impl GroupKind {
                         timestamp: key.timestamp,
                         labels: key.labels,
                         value,
+                        exemplar,
                     }));
                 }
-                metrics.insert(key, SimpleMetric { value });
+                if strict && metrics.contains_key(&key) {
+                    return Err(ParserError::DuplicateSample {
+                        name: metric.name,
+                        labels: key.labels,
+                    });
+                }
+                metrics.insert(key, SimpleMetric { value, exemplar });
             }
             Self::Histogram(ref mut metrics) => match suffix {
                 "_bucket" => {
@@ -175,9 +273,11 @@ impl GroupKind {
                         .map_err(Into::into)
                         .context(ParseLabelValueSnafu)?;
                     let count = try_f64_to_u64(metric.value)?;
-                    matching_group(metrics, key)
-                        .buckets
-                        .push(HistogramBucket { bucket, count });
+                    matching_group(metrics, key).buckets.push(HistogramBucket {
+                        bucket,
+                        count,
+                        exemplar,
+                    });
                 }
                 "_sum" => {
                     let sum = metric.value;
@@ -193,9 +293,24 @@ impl GroupKind {
                         timestamp: key.timestamp,
                         labels: key.labels,
                         value,
+                        exemplar,
                     }))
                 }
             },
+            // Native histograms only come from remote write histogram messages, never from float
+            // samples, so a float series of the same name would replace the whole group.
+            Self::NativeHistogram(_) if suffix.is_empty() => {
+                return Err(ParserError::MultipleMetricKinds { name: metric.name });
+            }
+            Self::NativeHistogram(_) => {
+                return Ok(Some(Metric {
+                    name: metric.name,
+                    timestamp: key.timestamp,
+                    labels: key.labels,
+                    value,
+                    exemplar,
+                }))
+            }
             Self::Summary(ref mut metrics) => match suffix {
                 "" => {
                     let quantile = key
@@ -224,6 +339,7 @@ impl GroupKind {
                         timestamp: key.timestamp,
                         labels: key.labels,
                         value,
+                        exemplar,
                     }))
                 }
             },
@@ -232,10 +348,14 @@ impl GroupKind {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct MetricGroup {
     pub name: String,
     pub metrics: GroupKind,
+    /// The unescaped text of the `# HELP` line for this metric, if any.
+    pub help: Option<String>,
+    /// The text of the `# UNIT` line for this metric, if any.
+    pub unit: Option<String>,
 }
 
 fn try_f64_to_u64(f: f64) -> Result<u64, ParserError> {
@@ -252,7 +372,12 @@ fn try_f64_to_u64(f: f64) -> Result<u64, ParserError> {
 impl MetricGroup {
     fn new(name: String, kind: MetricKind) -> Self {
         let metrics = GroupKind::new(kind);
-        MetricGroup { name, metrics }
+        MetricGroup {
+            name,
+            metrics,
+            help: None,
+            unit: None,
+        }
     }
 
     // For cases where a metric group was not defined with `# TYPE ...`.
@@ -262,22 +387,49 @@ impl MetricGroup {
             labels,
             value,
             timestamp,
+            exemplar,
         } = metric;
         let key = GroupKey { timestamp, labels };
         MetricGroup {
             name,
-            metrics: GroupKind::new_untyped(key, value),
+            metrics: GroupKind::new_untyped(key, SimpleMetric { value, exemplar }),
+            help: None,
+            unit: None,
+        }
+    }
+
+    /// Returns the exemplars attached to the samples of this group, along with the key of the
+    /// series they belong to. Only counters, untyped metrics, and histogram buckets carry
+    /// exemplars.
+    pub fn exemplars(&self) -> Vec<(&GroupKey, &Exemplar)> {
+        match &self.metrics {
+            GroupKind::Counter(metrics)
+            | GroupKind::Gauge(metrics)
+            | GroupKind::Untyped(metrics) => metrics
+                .iter()
+                .filter_map(|(key, metric)| Some((key, metric.exemplar.as_ref()?)))
+                .collect(),
+            GroupKind::Histogram(metrics) => metrics
+                .iter()
+                .flat_map(|(key, metric)| {
+                    metric
+                        .buckets
+                        .iter()
+                        .filter_map(move |bucket| Some((key, bucket.exemplar.as_ref()?)))
+                })
+                .collect(),
+            GroupKind::NativeHistogram(_) | GroupKind::Summary(_) => Vec::new(),
         }
     }
 
     /// `Err(_)` if there are irrecoverable error.
     /// `Ok(Some(metric))` if this metric belongs to another group.
     /// `Ok(None)` pushed successfully.
-    fn try_push(&mut self, metric: Metric) -> Result<Option<Metric>, ParserError> {
+    fn try_push(&mut self, metric: Metric, strict: bool) -> Result<Option<Metric>, ParserError> {
         if !metric.name.starts_with(&self.name) {
             return Ok(Some(metric));
         }
-        self.metrics.try_push(self.name.len(), metric)
+        self.metrics.try_push(self.name.len(), metric, strict)
     }
 }
 
@@ -288,31 +440,105 @@ fn matching_group<T: Default>(values: &mut MetricMap<T>, group: GroupKey) -> &mu
 /// Parse the given text input, and group the result into higher-level
 /// metric types based on the declared types in the text.
 pub fn parse_text(input: &str) -> Result<Vec<MetricGroup>, ParserError> {
-    let mut groups = Vec::new();
+    parse_text_iter(input).collect()
+}
+
+/// Parse the given text input like [`parse_text`], but reject a counter,
+/// gauge, or untyped sample that repeats the labels and timestamp of an
+/// earlier sample in the same group, instead of keeping the last one.
+pub fn parse_text_strict(input: &str) -> Result<Vec<MetricGroup>, ParserError> {
+    MetricGroupIter::new(input, true).collect()
+}
+
+/// Parse the given text input like [`parse_text`], but yield each metric
+/// group as soon as it is complete instead of collecting all of them first.
+/// Iteration stops after the first error.
+pub fn parse_text_iter(input: &str) -> impl Iterator<Item = Result<MetricGroup, ParserError>> + '_ {
+    MetricGroupIter::new(input, false)
+}
+
+struct MetricGroupIter<'a> {
+    lines: std::iter::Enumerate<std::str::Lines<'a>>,
+    current: Option<MetricGroup>,
+    // `# HELP` and `# UNIT` lines usually come before the `# TYPE` line that starts their group,
+    // so they are held here until that group starts.
+    pending_help: BTreeMap<String, String>,
+    pending_unit: BTreeMap<String, String>,
+    strict: bool,
+}
+
+impl<'a> MetricGroupIter<'a> {
+    fn new(input: &'a str, strict: bool) -> Self {
+        Self {
+            lines: input.lines().enumerate(),
+            current: None,
+            pending_help: BTreeMap::new(),
+            pending_unit: BTreeMap::new(),
+            strict,
+        }
+    }
 
-    for line in input.lines() {
+    /// `Ok(Some(group))` if this line completed the group that was being built.
+    fn push_line(&mut self, index: usize, line: &str) -> Result<Option<MetricGroup>, ParserError> {
         let line = Line::parse(line).with_context(|_| WithLineSnafu {
+            line_number: index + 1,
             line: line.to_owned(),
         })?;
-        if let Some(line) = line {
-            match line {
-                Line::Header(header) => {
-                    groups.push(MetricGroup::new(header.metric_name, header.kind));
+        let mut next = match line {
+            Some(Line::Header(header)) => MetricGroup::new(header.metric_name, header.kind),
+            Some(Line::Help(help)) => {
+                match self.current.as_mut() {
+                    Some(group) if group.name == help.metric_name => group.help = Some(help.text),
+                    _ => {
+                        self.pending_help.insert(help.metric_name, help.text);
+                    }
                 }
-                Line::Metric(metric) => {
-                    let metric = match groups.last_mut() {
-                        Some(group) => group.try_push(metric)?,
-                        None => Some(metric),
-                    };
-                    if let Some(metric) = metric {
-                        groups.push(MetricGroup::new_untyped(metric));
+                return Ok(None);
+            }
+            Some(Line::Unit(unit)) => {
+                match self.current.as_mut() {
+                    Some(group) if group.name == unit.metric_name => group.unit = Some(unit.text),
+                    _ => {
+                        self.pending_unit.insert(unit.metric_name, unit.text);
                     }
                 }
+                return Ok(None);
             }
-        }
+            Some(Line::Metric(metric)) => {
+                let metric = match self.current.as_mut() {
+                    Some(group) => group.try_push(metric, self.strict)?,
+                    None => Some(metric),
+                };
+                match metric {
+                    Some(metric) => MetricGroup::new_untyped(metric),
+                    None => return Ok(None),
+                }
+            }
+            None => return Ok(None),
+        };
+        next.help = self.pending_help.remove(&next.name);
+        next.unit = self.pending_unit.remove(&next.name);
+        Ok(self.current.replace(next))
     }
+}
 
-    Ok(groups)
+impl Iterator for MetricGroupIter<'_> {
+    type Item = Result<MetricGroup, ParserError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((index, line)) = self.lines.next() {
+            match self.push_line(index, line) {
+                Ok(Some(group)) => return Some(Ok(group)),
+                Ok(None) => {}
+                Err(error) => {
+                    self.lines = "".lines().enumerate();
+                    self.current = None;
+                    return Some(Err(error));
+                }
+            }
+        }
+        self.current.take().map(Ok)
+    }
 }
 
 #[derive(Default)]
@@ -364,22 +590,64 @@ impl MetricGroupSet {
                 labels: labels.clone(),
                 value: sample.value,
                 timestamp: Some(sample.timestamp),
+                exemplar: None,
             },
+            false,
         )? {
             let key = GroupKey {
                 timestamp: metric.timestamp,
                 labels: metric.labels,
             };
-            let group = GroupKind::new_untyped(key, metric.value);
+            let group = GroupKind::new_untyped(
+                key,
+                SimpleMetric {
+                    value: metric.value,
+                    exemplar: None,
+                },
+            );
             self.0.insert(metric.name, group);
         }
         Ok(())
     }
 
+    fn insert_histogram(
+        &mut self,
+        name: &str,
+        labels: &BTreeMap<String, String>,
+        histogram: proto::Histogram,
+    ) -> Result<(), ParserError> {
+        let group = self
+            .0
+            .entry(name.into())
+            .or_insert_with(|| GroupKind::NativeHistogram(IndexMap::default()));
+        // A series declared as a histogram in the metadata starts out as a classic histogram
+        // group, so switch it over as long as no classic buckets have been seen for it.
+        if matches!(group, GroupKind::Histogram(_) | GroupKind::Untyped(_)) && group.is_empty() {
+            *group = GroupKind::NativeHistogram(IndexMap::default());
+        }
+
+        match group {
+            GroupKind::NativeHistogram(metrics) => {
+                let key = GroupKey {
+                    timestamp: Some(histogram.timestamp),
+                    labels: labels.clone(),
+                };
+                metrics.insert(key, histogram.into());
+                Ok(())
+            }
+            _ => Err(ParserError::MultipleMetricKinds { name: name.into() }),
+        }
+    }
+
     fn finish(self) -> Vec<MetricGroup> {
         self.0
             .into_iter()
-            .map(|(name, metrics)| MetricGroup { name, metrics })
+            .map(|(name, metrics)| MetricGroup {
+                name,
+                metrics,
+                help: None,
+                unit: None,
+            })
             .collect()
     }
 }
@@ -411,6 +679,10 @@ pub fn parse_request(request: proto::WriteRequest) -> Result<Vec<MetricGroup>, P
         for sample in timeseries.samples {
             groups.insert_sample(&name, &labels, sample)?;
         }
+
+        for histogram in timeseries.histograms {
+            groups.insert_histogram(&name, &labels, histogram)?;
+        }
     }
 
     Ok(groups.finish())
@@ -461,60 +733,70 @@ mod test {
                     timestamp: $timestamp,
                     labels: $labels,
                 },
-                &SimpleMetric { value: $value },
+                &SimpleMetric {
+                    value: $value,
+                    exemplar: None,
+                },
             )
         };
     }
 
+    const MULTI_METRIC_INPUT: &str = r#"
+        # HELP http_requests_total The total number of HTTP requests.
+        # TYPE http_requests_total counter
+        http_requests_total{method="post",code="200"} 1027 1395066363000
+        http_requests_total{method="post",code="400"}    3 1395066363000
+
+        # Escaping in label values:
+        msdos_file_access_time_seconds{path="C:\\DIR\\FILE.TXT",error="Cannot find file:\n\"FILE.TXT\""} 1.458255915e9
+
+        # Minimalistic line:
+        metric_without_timestamp_and_labels 12.47
+
+        # A weird metric from before the epoch:
+        something_weird{problem="division by zero"} +Inf -3982045
+
+        # A histogram, which has a pretty complex representation in the text format:
+        # HELP http_request_duration_seconds A histogram of the request duration.
+        # TYPE http_request_duration_seconds histogram
+        http_request_duration_seconds_bucket{le="0.05"} 24054
+        http_request_duration_seconds_bucket{le="0.1"} 33444
+        http_request_duration_seconds_bucket{le="0.2"} 100392
+        http_request_duration_seconds_bucket{le="0.5"} 129389
+        http_request_duration_seconds_bucket{le="1"} 133988
+        http_request_duration_seconds_bucket{le="+Inf"} 144320
+        http_request_duration_seconds_sum 53423
+        http_request_duration_seconds_count 144320
+
+        # A histogram with a very large count.
+        # HELP go_gc_heap_allocs_by_size_bytes A histogram of something gc
+        # TYPE go_gc_heap_allocs_by_size_bytes histogram
+        go_gc_heap_allocs_by_size_bytes_bucket{le="24.999999999999996"} 1.8939392877e+10
+        go_gc_heap_allocs_by_size_bytes_sum 5
+        go_gc_heap_allocs_by_size_bytes_count 10
+
+        # Finally a summary, which has a complex representation, too:
+        # HELP rpc_duration_seconds A summary of the RPC duration in seconds.
+        # TYPE rpc_duration_seconds summary
+        rpc_duration_seconds{quantile="0.01"} 3102
+        rpc_duration_seconds{quantile="0.05"} 3272
+        rpc_duration_seconds{quantile="0.5"} 4773
+        rpc_duration_seconds{quantile="0.9"} 9001
+        rpc_duration_seconds{quantile="0.99"} 76656
+        rpc_duration_seconds_sum 1.7560473e+07
+        rpc_duration_seconds_count 4.588206224e+09
+        "#;
+
     #[test]
     fn test_parse_text() {
-        let input = r#"
-            # HELP http_requests_total The total number of HTTP requests.
-            # TYPE http_requests_total counter
-            http_requests_total{method="post",code="200"} 1027 1395066363000
-            http_requests_total{method="post",code="400"}    3 1395066363000
-
-            # Escaping in label values:
-            msdos_file_access_time_seconds{path="C:\\DIR\\FILE.TXT",error="Cannot find file:\n\"FILE.TXT\""} 1.458255915e9
-
-            # Minimalistic line:
-            metric_without_timestamp_and_labels 12.47
-
-            # A weird metric from before the epoch:
-            something_weird{problem="division by zero"} +Inf -3982045
-
-            # A histogram, which has a pretty complex representation in the text format:
-            # HELP http_request_duration_seconds A histogram of the request duration.
-            # TYPE http_request_duration_seconds histogram
-            http_request_duration_seconds_bucket{le="0.05"} 24054
-            http_request_duration_seconds_bucket{le="0.1"} 33444
-            http_request_duration_seconds_bucket{le="0.2"} 100392
-            http_request_duration_seconds_bucket{le="0.5"} 129389
-            http_request_duration_seconds_bucket{le="1"} 133988
-            http_request_duration_seconds_bucket{le="+Inf"} 144320
-            http_request_duration_seconds_sum 53423
-            http_request_duration_seconds_count 144320
-
-            # A histogram with a very large count.
-            # HELP go_gc_heap_allocs_by_size_bytes A histogram of something gc
-            # TYPE go_gc_heap_allocs_by_size_bytes histogram
-            go_gc_heap_allocs_by_size_bytes_bucket{le="24.999999999999996"} 1.8939392877e+10
-            go_gc_heap_allocs_by_size_bytes_sum 5
-            go_gc_heap_allocs_by_size_bytes_count 10
-
-            # Finally a summary, which has a complex representation, too:
-            # HELP rpc_duration_seconds A summary of the RPC duration in seconds.
-            # TYPE rpc_duration_seconds summary
-            rpc_duration_seconds{quantile="0.01"} 3102
-            rpc_duration_seconds{quantile="0.05"} 3272
-            rpc_duration_seconds{quantile="0.5"} 4773
-            rpc_duration_seconds{quantile="0.9"} 9001
-            rpc_duration_seconds{quantile="0.99"} 76656
-            rpc_duration_seconds_sum 1.7560473e+07
-            rpc_duration_seconds_count 4.588206224e+09
-            "#;
+        let input = MULTI_METRIC_INPUT;
         let output = parse_text(input).unwrap();
         assert_eq!(output.len(), 7);
+        assert_eq!(
+            output[0].help.as_deref(),
+            Some("The total number of HTTP requests.")
+        );
+        assert_eq!(output[1].help, None);
         match_group!(output[0], "http_requests_total", Counter => |metrics: &MetricMap<SimpleMetric>| {
             assert_eq!(metrics.len(), 2);
             assert_eq!(
@@ -554,12 +836,12 @@ mod test {
                 },
                 &HistogramMetric {
                     buckets: vec![
-                        HistogramBucket { bucket: 0.05, count: 24054 },
-                        HistogramBucket { bucket: 0.1, count: 33444 },
-                        HistogramBucket { bucket: 0.2, count: 100392 },
-                        HistogramBucket { bucket: 0.5, count: 129389 },
-                        HistogramBucket { bucket: 1.0, count: 133988 },
-                        HistogramBucket { bucket: f64::INFINITY, count: 144320 },
+                        HistogramBucket { bucket: 0.05, count: 24054, exemplar: None },
+                        HistogramBucket { bucket: 0.1, count: 33444, exemplar: None },
+                        HistogramBucket { bucket: 0.2, count: 100392, exemplar: None },
+                        HistogramBucket { bucket: 0.5, count: 129389, exemplar: None },
+                        HistogramBucket { bucket: 1.0, count: 133988, exemplar: None },
+                        HistogramBucket { bucket: f64::INFINITY, count: 144320, exemplar: None },
                     ],
                     count: 144320,
                     sum: 53423.0,
@@ -575,7 +857,7 @@ mod test {
                 },
                 &HistogramMetric {
                     buckets: vec![
-                        HistogramBucket { bucket: 24.999999999999996, count: 18_939_392_877},
+                        HistogramBucket { bucket: 24.999999999999996, count: 18_939_392_877, exemplar: None },
                     ],
                     count: 10,
                     sum: 5.0,
@@ -604,6 +886,73 @@ mod test {
         });
     }
 
+    #[test]
+    fn test_parse_text_exemplars() {
+        let input = r#"
+            # TYPE http_requests_total counter
+            http_requests_total{code="200"} 1027 # {trace_id="abc"} 1 1520879607.789
+            http_requests_total{code="400"} 3
+
+            # TYPE http_request_duration_seconds histogram
+            http_request_duration_seconds_bucket{le="0.05"} 24054
+            http_request_duration_seconds_bucket{le="0.1"} 33444 # {trace_id="def"} 0.07 1520879607.789
+            http_request_duration_seconds_bucket{le="+Inf"} 144320 # {trace_id="ghi"} 3.2
+            http_request_duration_seconds_sum 53423
+            http_request_duration_seconds_count 144320
+            "#;
+        let output = parse_text(input).unwrap();
+        assert_eq!(output.len(), 2);
+
+        let exemplar = |trace_id: &str, value: f64, timestamp: Option<f64>| Exemplar {
+            labels: BTreeMap::from([("trace_id".to_owned(), trace_id.to_owned())]),
+            value,
+            timestamp,
+        };
+
+        match_group!(output[0], "http_requests_total", Counter => |metrics: &MetricMap<SimpleMetric>| {
+            assert_eq!(metrics.len(), 2);
+            assert_eq!(
+                metrics.get_index(0).unwrap().1,
+                &SimpleMetric {
+                    value: 1027.0,
+                    exemplar: Some(exemplar("abc", 1.0, Some(1520879607.789))),
+                }
+            );
+            assert_eq!(
+                metrics.get_index(1).unwrap().1,
+                &SimpleMetric {
+                    value: 3.0,
+                    exemplar: None,
+                }
+            );
+        });
+        match_group!(output[1], "http_request_duration_seconds", Histogram => |metrics: &MetricMap<HistogramMetric>| {
+            assert_eq!(metrics.len(), 1);
+            assert_eq!(
+                metrics.get_index(0).unwrap().1.buckets,
+                vec![
+                    HistogramBucket { bucket: 0.05, count: 24054, exemplar: None },
+                    HistogramBucket {
+                        bucket: 0.1,
+                        count: 33444,
+                        exemplar: Some(exemplar("def", 0.07, Some(1520879607.789))),
+                    },
+                    HistogramBucket {
+                        bucket: f64::INFINITY,
+                        count: 144320,
+                        exemplar: Some(exemplar("ghi", 3.2, None)),
+                    },
+                ]
+            );
+        });
+
+        let exemplars = output[1].exemplars();
+        assert_eq!(exemplars.len(), 2);
+        assert_eq!(exemplars[0].1, &exemplar("def", 0.07, Some(1520879607.789)));
+        assert_eq!(exemplars[1].1, &exemplar("ghi", 3.2, None));
+        assert_eq!(output[0].exemplars().len(), 1);
+    }
+
     #[test]
     fn test_f64_to_u64() {
         let value = -1.0;
@@ -656,6 +1005,7 @@ mod test {
         assert!(matches!(
             error,
             ParserError::WithLine {
+                line_number: 1,
                 kind: ErrorKind::ExpectedChar { expected: ',', .. },
                 ..
             }
@@ -666,6 +1016,7 @@ mod test {
         assert!(matches!(
             error,
             ParserError::WithLine {
+                line_number: 1,
                 kind: ErrorKind::InvalidMetricKind { .. },
                 ..
             }
@@ -676,6 +1027,7 @@ mod test {
         assert!(matches!(
             error,
             ParserError::WithLine {
+                line_number: 1,
                 kind: ErrorKind::ExpectedSpace { .. },
                 ..
             }
@@ -686,6 +1038,7 @@ mod test {
         assert!(matches!(
             error,
             ParserError::WithLine {
+                line_number: 1,
                 kind: ErrorKind::ExpectedChar { expected: '"', .. },
                 ..
             }
@@ -696,6 +1049,7 @@ mod test {
         assert!(matches!(
             error,
             ParserError::WithLine {
+                line_number: 1,
                 kind: ErrorKind::ExpectedChar { expected: '"', .. },
                 ..
             }
@@ -706,12 +1060,182 @@ mod test {
         assert!(matches!(
             error,
             ParserError::WithLine {
+                line_number: 1,
                 kind: ErrorKind::ParseFloatError { .. },
                 ..
             }
         ));
     }
 
+    #[test]
+    fn test_parse_text_duplicate_sample() {
+        let input = r#"
+            # TYPE http_requests_total counter
+            http_requests_total{code="200"} 1027 1395066363000
+            http_requests_total{code="400"} 3 1395066363000
+            http_requests_total{code="200"} 1030 1395066363000
+            "#;
+
+        let output = parse_text(input).unwrap();
+        assert_eq!(output.len(), 1);
+        match_group!(output[0], "http_requests_total", Counter => |metrics: &MetricMap<SimpleMetric>| {
+            assert_eq!(metrics.len(), 2);
+            assert_eq!(
+                metrics.get_index(0).unwrap(),
+                simple_metric!(Some(1395066363000), labels!(code => 200), 1030.0)
+            );
+        });
+
+        assert_eq!(
+            parse_text_strict(input).unwrap_err(),
+            ParserError::DuplicateSample {
+                name: "http_requests_total".into(),
+                labels: labels!(code => 200),
+            }
+        );
+
+        // Different timestamps are distinct samples.
+        let input = r#"
+            # TYPE http_requests_total counter
+            http_requests_total{code="200"} 1027 1395066363000
+            http_requests_total{code="200"} 1030 1395066364000
+            "#;
+        assert_eq!(
+            parse_text_strict(input).unwrap(),
+            parse_text(input).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_error_line_number() {
+        let input = "# TYPE a counter\na 1\n\nb{c=\"d\" 2\n";
+        let error = parse_text(input).unwrap_err();
+        assert_eq!(
+            error,
+            ParserError::WithLine {
+                line_number: 4,
+                line: r#"b{c="d" 2"#.into(),
+                kind: ErrorKind::ExpectedChar {
+                    expected: ',',
+                    input: " 2".into(),
+                },
+            }
+        );
+        assert!(error.to_string().starts_with("line 4: "));
+    }
+
+    #[test]
+    fn test_parse_text_help_and_unit() {
+        let input = r#"
+            # TYPE request_duration_seconds histogram
+            # UNIT request_duration_seconds seconds
+            # HELP request_duration_seconds Request duration.\nIncludes queueing in C:\\queue.
+            request_duration_seconds_sum 5
+            # HELP other_total Not the next metric.
+            # HELP queue_depth Current depth.
+            queue_depth 3
+            "#;
+        let output = parse_text(input).unwrap();
+        assert_eq!(output.len(), 2);
+
+        assert_eq!(output[0].name, "request_duration_seconds");
+        assert_eq!(
+            output[0].help.as_deref(),
+            Some("Request duration.\nIncludes queueing in C:\\queue.")
+        );
+        assert_eq!(output[0].unit.as_deref(), Some("seconds"));
+
+        assert_eq!(output[1].name, "queue_depth");
+        assert_eq!(output[1].help.as_deref(), Some("Current depth."));
+        assert_eq!(output[1].unit, None);
+    }
+
+    #[test]
+    fn test_parse_text_iter() {
+        let mut groups = parse_text_iter(MULTI_METRIC_INPUT);
+
+        let group = groups.next().unwrap().unwrap();
+        match_group!(group, "http_requests_total", Counter => |metrics: &MetricMap<SimpleMetric>| {
+            assert_eq!(metrics.len(), 2);
+        });
+        // Untyped groups end where the next metric name starts.
+        let group = groups.next().unwrap().unwrap();
+        match_group!(group, "msdos_file_access_time_seconds", Untyped => |metrics: &MetricMap<SimpleMetric>| {
+            assert_eq!(metrics.len(), 1);
+        });
+        let group = groups.next().unwrap().unwrap();
+        match_group!(group, "metric_without_timestamp_and_labels", Untyped => |metrics: &MetricMap<SimpleMetric>| {
+            assert_eq!(metrics.len(), 1);
+        });
+        let group = groups.next().unwrap().unwrap();
+        match_group!(group, "something_weird", Untyped => |metrics: &MetricMap<SimpleMetric>| {
+            assert_eq!(metrics.len(), 1);
+        });
+        let group = groups.next().unwrap().unwrap();
+        match_group!(group, "http_request_duration_seconds", Histogram => |metrics: &MetricMap<HistogramMetric>| {
+            assert_eq!(metrics.len(), 1);
+        });
+        let group = groups.next().unwrap().unwrap();
+        match_group!(group, "go_gc_heap_allocs_by_size_bytes", Histogram => |metrics: &MetricMap<HistogramMetric>| {
+            assert_eq!(metrics.len(), 1);
+        });
+        let group = groups.next().unwrap().unwrap();
+        match_group!(group, "rpc_duration_seconds", Summary => |metrics: &MetricMap<SummaryMetric>| {
+            assert_eq!(metrics.len(), 1);
+        });
+        assert!(groups.next().is_none());
+    }
+
+    #[test]
+    fn test_parse_text_iter_yields_before_rest_of_input() {
+        let input = r#"
+            # TYPE one counter
+            one 1
+            two 2
+            three 3
+            four{ 4
+            "#;
+        let mut groups = parse_text_iter(input);
+
+        // Each group is yielded once the next one starts, before the invalid last line is read.
+        let group = groups.next().unwrap().unwrap();
+        match_group!(group, "one", Counter => |metrics: &MetricMap<SimpleMetric>| {
+            assert_eq!(metrics.get_index(0).unwrap(), simple_metric!(None, labels!(), 1.0));
+        });
+        let group = groups.next().unwrap().unwrap();
+        match_group!(group, "two", Untyped => |metrics: &MetricMap<SimpleMetric>| {
+            assert_eq!(metrics.get_index(0).unwrap(), simple_metric!(None, labels!(), 2.0));
+        });
+        assert!(matches!(
+            groups.next(),
+            Some(Err(ParserError::WithLine { .. }))
+        ));
+        assert!(groups.next().is_none());
+    }
+
+    #[test]
+    fn test_parse_text_iter_stops_at_error() {
+        let input = r#"
+            # TYPE one counter
+            one 1
+            two 2
+            three{ 3
+            four 4
+            "#;
+        let mut groups = parse_text_iter(input);
+
+        let group = groups.next().unwrap().unwrap();
+        match_group!(group, "one", Counter => |metrics: &MetricMap<SimpleMetric>| {
+            assert_eq!(metrics.get_index(0).unwrap(), simple_metric!(None, labels!(), 1.0));
+        });
+        // `two` is still being built when the invalid line is reached, so it is never yielded.
+        assert!(matches!(
+            groups.next(),
+            Some(Err(ParserError::WithLine { .. }))
+        ));
+        assert!(groups.next().is_none());
+    }
+
     macro_rules! write_request {
         (
             [ $( $name:literal = $type:ident ),* ],
@@ -736,6 +1260,7 @@ mod test {
                     samples: vec![
                         $( proto::Sample { value: $sample as f64, timestamp: $timestamp as i64 }, )*
                     ],
+                    histograms: vec![],
                 }, )* ],
             }
         };
@@ -834,8 +1359,8 @@ mod test {
                     },
                     &HistogramMetric {
                         buckets: vec![
-                            HistogramBucket { bucket: 1.0, count: 15 },
-                            HistogramBucket { bucket: f64::INFINITY, count: 19 },
+                            HistogramBucket { bucket: 1.0, count: 15, exemplar: None },
+                            HistogramBucket { bucket: f64::INFINITY, count: 19, exemplar: None },
                         ],
                         count: 19,
                         sum: 12.0,
@@ -886,4 +1411,148 @@ mod test {
             assert_eq!(metrics.get_index(0).unwrap(), simple_metric!(Some(1395066367700), labels!(), 24.0));
         });
     }
+
+    #[test]
+    fn parse_request_native_histogram() {
+        use prost::Message;
+
+        let mut request = write_request!(
+            ["one" = Histogram],
+            [ [__name__ => "two", big => "small"] => [ 12 @ 1395066367700 ] ]
+        );
+        request.timeseries.push(proto::TimeSeries {
+            labels: vec![
+                proto::Label {
+                    name: METRIC_NAME_LABEL.into(),
+                    value: "one".into(),
+                },
+                proto::Label {
+                    name: "big".into(),
+                    value: "small".into(),
+                },
+            ],
+            samples: vec![],
+            histograms: vec![proto::Histogram {
+                count: Some(proto::histogram::Count::CountInt(12)),
+                sum: 18.4,
+                schema: 1,
+                zero_threshold: 0.001,
+                zero_count: Some(proto::histogram::ZeroCount::ZeroCountInt(2)),
+                negative_spans: vec![proto::BucketSpan {
+                    offset: 0,
+                    length: 2,
+                }],
+                negative_deltas: vec![1, 1],
+                negative_counts: vec![],
+                positive_spans: vec![
+                    proto::BucketSpan {
+                        offset: 0,
+                        length: 2,
+                    },
+                    proto::BucketSpan {
+                        offset: 1,
+                        length: 2,
+                    },
+                ],
+                positive_deltas: vec![1, 1, -1, 0],
+                positive_counts: vec![],
+                reset_hint: proto::histogram::ResetHint::No as i32,
+                timestamp: 1395066367700,
+            }],
+        });
+
+        let encoded = request.encode_to_vec();
+        let decoded = proto::WriteRequest::decode(encoded.as_slice()).unwrap();
+        assert_eq!(decoded, request);
+
+        let parsed = parse_request(decoded).unwrap();
+        assert_eq!(parsed.len(), 2);
+        match_group!(parsed[0], "one", NativeHistogram => |metrics: &MetricMap<NativeHistogramMetric>| {
+            assert_eq!(metrics.len(), 1);
+            assert_eq!(
+                metrics.get_index(0).unwrap(), (
+                    &GroupKey {
+                        timestamp: Some(1395066367700),
+                        labels: labels!(big => "small"),
+                    },
+                    &NativeHistogramMetric {
+                        schema: 1,
+                        zero_threshold: 0.001,
+                        zero_count: 2.0,
+                        count: 12.0,
+                        sum: 18.4,
+                        positive_spans: vec![
+                            BucketSpan { offset: 0, length: 2 },
+                            BucketSpan { offset: 1, length: 2 },
+                        ],
+                        positive_deltas: vec![1, 1, -1, 0],
+                        positive_counts: vec![],
+                        negative_spans: vec![BucketSpan { offset: 0, length: 2 }],
+                        negative_deltas: vec![1, 1],
+                        negative_counts: vec![],
+                    })
+            );
+        });
+        match_group!(parsed[1], "two", Untyped => |metrics: &MetricMap<SimpleMetric>| {
+            assert_eq!(metrics.len(), 1);
+            assert_eq!(
+                metrics.get_index(0).unwrap(),
+                simple_metric!(Some(1395066367700), labels!(big => "small"), 12.0)
+            );
+        });
+    }
+
+    #[test]
+    fn parse_request_native_histogram_after_classic() {
+        let mut request = write_request!(
+            ["one" = Histogram],
+            [ [__name__ => "one_count"] => [ 19 @ 1395066367700 ] ]
+        );
+        request.timeseries.push(proto::TimeSeries {
+            labels: vec![proto::Label {
+                name: METRIC_NAME_LABEL.into(),
+                value: "one".into(),
+            }],
+            samples: vec![],
+            histograms: vec![proto::Histogram::default()],
+        });
+
+        assert_eq!(
+            parse_request(request).unwrap_err(),
+            ParserError::MultipleMetricKinds { name: "one".into() }
+        );
+    }
+
+    #[test]
+    fn parse_request_float_after_native_histogram() {
+        let request = proto::WriteRequest {
+            metadata: vec![],
+            timeseries: vec![
+                proto::TimeSeries {
+                    labels: vec![proto::Label {
+                        name: METRIC_NAME_LABEL.into(),
+                        value: "one".into(),
+                    }],
+                    samples: vec![],
+                    histograms: vec![proto::Histogram::default()],
+                },
+                proto::TimeSeries {
+                    labels: vec![proto::Label {
+                        name: METRIC_NAME_LABEL.into(),
+                        value: "one".into(),
+                    }],
+                    samples: vec![proto::Sample {
+                        value: 12.0,
+                        timestamp: 1395066367700,
+                    }],
+                    histograms: vec![],
+                },
+            ],
+        };
+
+        assert_eq!(
+            parse_request(request).unwrap_err(),
+            ParserError::MultipleMetricKinds { name: "one".into() }
+        );
+    }
 }
@@ -94,12 +94,29 @@ pub struct Header {
     pub kind: MetricKind,
 }
 
+/// The text of a `# HELP` or `# UNIT` line, along with the metric it describes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Descriptor {
+    pub metric_name: String,
+    pub text: String,
+}
+
+/// An OpenMetrics exemplar, linking a sample to an example event such as a trace.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct Exemplar {
+    pub labels: BTreeMap<String, String>,
+    pub value: f64,
+    /// Seconds since the epoch.
+    pub timestamp: Option<f64>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Metric {
     pub name: String,
     pub labels: BTreeMap<String, String>,
     pub value: f64,
     pub timestamp: Option<i64>,
+    pub exemplar: Option<Exemplar>,
 }
 
 impl Metric {
@@ -108,16 +125,15 @@ impl Metric {
     /// ``` text
     /// metric_name [
     ///   "{" label_name "=" `"` label_value `"` { "," label_name "=" `"` label_value `"` } [ "," ] "}"
-    /// ] value [ timestamp ]
+    /// ] value [ timestamp ] [ exemplar ]
     /// ```
-    ///
-    /// We don't parse timestamp.
     fn parse(input: &str) -> IResult<Self> {
         let input = trim_space(input);
         let (input, name) = parse_name(input)?;
         let (input, labels) = Self::parse_labels(input)?;
         let (input, value) = Self::parse_value(input)?;
         let (input, timestamp) = Self::parse_timestamp(input)?;
+        let (input, exemplar) = Self::parse_exemplar(input)?;
         Ok((
             input,
             Metric {
@@ -125,10 +141,46 @@ impl Metric {
                 labels,
                 value,
                 timestamp,
+                exemplar,
             },
         ))
     }
 
+    /// Parse an optional OpenMetrics exemplar with format
+    ///
+    /// ``` text
+    /// "#" "{" label_name "=" `"` label_value `"` { "," label_name "=" `"` label_value `"` } [ "," ] "}" value [ timestamp ]
+    /// ```
+    ///
+    /// Unlike the sample timestamp, the exemplar timestamp is a float number of seconds.
+    fn parse_exemplar(input: &str) -> IResult<Option<Exemplar>> {
+        let input = trim_space(input);
+        let Ok((input, _)) = char::<_, NomErrorType>('#')(input) else {
+            return Ok((input, None));
+        };
+        let (input, _) = match_char('{')(input)?;
+        let (input, labels) = Self::parse_labels_inner(input)?;
+        let (input, value) = Self::parse_value(input)?;
+        let input = trim_space(input);
+        let (input, timestamp) = if input.is_empty() {
+            (input, None)
+        } else {
+            let (input, timestamp) =
+                double(input).map_err(|_: NomError| ErrorKind::ParseTimestampError {
+                    input: input.to_owned(),
+                })?;
+            (input, Some(timestamp))
+        };
+        Ok((
+            input,
+            Some(Exemplar {
+                labels,
+                value,
+                timestamp,
+            }),
+        ))
+    }
+
     /// Float value, and +Inf, -Int, Nan.
     pub(crate) fn parse_value(input: &str) -> IResult<f64> {
         let input = trim_space(input);
@@ -318,11 +370,84 @@ impl Header {
     }
 }
 
+impl Descriptor {
+    /// `# HELP <metric_name> <docstring>`
+    ///
+    /// Backslashes and newlines in the docstring are escaped as `\\` and `\n`.
+    fn parse_help(input: &str) -> IResult<Self> {
+        let (input, descriptor) = Self::parse_with_token(input, "HELP")?;
+        Ok((
+            input,
+            Descriptor {
+                text: unescape_help(&descriptor.text),
+                ..descriptor
+            },
+        ))
+    }
+
+    /// `# UNIT <metric_name> <unit>`
+    fn parse_unit(input: &str) -> IResult<Self> {
+        Self::parse_with_token(input, "UNIT")
+    }
+
+    fn parse_with_token<'a>(input: &'a str, token: &'static str) -> IResult<'a, Self> {
+        let input = trim_space(input);
+        let (input, _) = char('#')(input).map_err(|_: NomError| ErrorKind::ExpectedChar {
+            expected: '#',
+            input: input.to_owned(),
+        })?;
+        let input = trim_space(input);
+        let (input, _) = tag(token)(input).map_err(|_: NomError| ErrorKind::ExpectedToken {
+            expected: token,
+            input: input.to_owned(),
+        })?;
+        let (input, _) = Header::space1(input)?;
+        let (input, metric_name) = parse_name(input)?;
+        // The text is optional, but must be separated from the name when present.
+        let text = if input.is_empty() {
+            input
+        } else {
+            Header::space1(input)?.0
+        };
+        Ok((
+            "",
+            Descriptor {
+                metric_name,
+                text: text.to_owned(),
+            },
+        ))
+    }
+}
+
+/// Unescape `\\` and `\n` in a HELP docstring, leaving any other backslash as is.
+fn unescape_help(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+    let mut chars = input.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('\\') => result.push('\\'),
+            Some('n') => result.push('\n'),
+            Some(c) => {
+                result.push('\\');
+                result.push(c);
+            }
+            None => result.push('\\'),
+        }
+    }
+    result
+}
+
 /// Each line of Prometheus text format.
 /// We discard empty lines, comments, and timestamps.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Line {
     Header(Header),
+    Help(Descriptor),
+    Unit(Descriptor),
     Metric(Metric),
 }
 
@@ -348,6 +473,13 @@ impl Line {
             Err(e) => e.into(),
         };
 
+        if let Ok((_, help)) = Descriptor::parse_help(input) {
+            return Ok(Some(Line::Help(help)));
+        }
+        if let Ok((_, unit)) = Descriptor::parse_unit(input) {
+            return Ok(Some(Line::Unit(unit)));
+        }
+
         if let Ok((input, _)) = char::<_, NomErrorType>('#')(input) {
             if tuple::<_, _, NomErrorType, _>((sp, tag("TYPE")))(input).is_ok() {
                 return Err(header_error);
@@ -669,6 +801,120 @@ mod test {
         assert_eq!(Metric::parse_timestamp(" -23"), Ok(("", Some(-23))));
     }
 
+    #[test]
+    fn test_parse_exemplar() {
+        assert_eq!(Metric::parse_exemplar(""), Ok(("", None)));
+        assert_eq!(Metric::parse_exemplar("  "), Ok(("", None)));
+
+        let input = r#" # {trace_id="abc",span_id="def"} 0.07 1520879607.789"#;
+        let (left, r) = Metric::parse_exemplar(input).unwrap();
+        assert_eq!(left, "");
+        assert_eq!(
+            r,
+            Some(Exemplar {
+                labels: btreemap! {"trace_id" => "abc", "span_id" => "def"},
+                value: 0.07,
+                timestamp: Some(1520879607.789),
+            })
+        );
+
+        let input = r#"# {} 1"#;
+        let (left, r) = Metric::parse_exemplar(input).unwrap();
+        assert_eq!(left, "");
+        assert_eq!(
+            r,
+            Some(Exemplar {
+                labels: BTreeMap::new(),
+                value: 1.0,
+                timestamp: None,
+            })
+        );
+
+        let input = r#"# trace_id="abc" 1"#;
+        let error = Metric::parse_exemplar(input).unwrap_err().into();
+        assert!(matches!(
+            error,
+            ErrorKind::ExpectedChar { expected: '{', .. }
+        ));
+
+        let input = r#"# {trace_id="abc"} 1 abc"#;
+        let error = Metric::parse_exemplar(input).unwrap_err().into();
+        assert!(matches!(error, ErrorKind::ParseTimestampError { .. }));
+    }
+
+    #[test]
+    fn test_parse_metric_with_exemplar() {
+        let input = r#"http_request_duration_seconds_bucket{le="0.1"} 33444 # {trace_id="abc"} 0.07 1520879607.789"#;
+        let (_, r) = Metric::parse(input).unwrap();
+        assert_eq!(
+            r,
+            Metric {
+                name: "http_request_duration_seconds_bucket".into(),
+                labels: btreemap! {"le" => "0.1"},
+                value: 33444.0,
+                timestamp: None,
+                exemplar: Some(Exemplar {
+                    labels: btreemap! {"trace_id" => "abc"},
+                    value: 0.07,
+                    timestamp: Some(1520879607.789),
+                }),
+            }
+        );
+
+        let input = r#"foo_total 17 1520879607789 # {trace_id="abc"} 1"#;
+        let (_, r) = Metric::parse(input).unwrap();
+        assert_eq!(r.timestamp, Some(1520879607789));
+        assert_eq!(
+            r.exemplar,
+            Some(Exemplar {
+                labels: btreemap! {"trace_id" => "abc"},
+                value: 1.0,
+                timestamp: None,
+            })
+        );
+
+        let input = "foo_total 17 1520879607789";
+        let (_, r) = Metric::parse(input).unwrap();
+        assert_eq!(r.exemplar, None);
+    }
+
+    #[test]
+    fn test_parse_help() {
+        let (_, r) = Descriptor::parse_help("# HELP abc_def The number of things.").unwrap();
+        assert_eq!(
+            r,
+            Descriptor {
+                metric_name: "abc_def".into(),
+                text: "The number of things.".into(),
+            }
+        );
+
+        let (_, r) = Descriptor::parse_help(r"#HELP abc_def  C:\\DIR\nnext line \d").unwrap();
+        assert_eq!(r.text, "C:\\DIR\nnext line \\d");
+
+        let (_, r) = Descriptor::parse_help("# HELP abc_def").unwrap();
+        assert_eq!(r.text, "");
+
+        assert!(Descriptor::parse_help("# HELP abc_def{} text").is_err());
+        assert!(Descriptor::parse_help("# TYPE abc_def counter").is_err());
+    }
+
+    #[test]
+    fn test_parse_unit() {
+        let (_, r) = Descriptor::parse_unit("# UNIT abc_def_seconds seconds").unwrap();
+        assert_eq!(
+            r,
+            Descriptor {
+                metric_name: "abc_def_seconds".into(),
+                text: "seconds".into(),
+            }
+        );
+
+        // Units are not escaped.
+        let (_, r) = Descriptor::parse_unit(r"# UNIT abc_def a\nb").unwrap();
+        assert_eq!(r.text, r"a\nb");
+    }
+
     #[test]
     fn test_parse_line() {
         let input = r#"
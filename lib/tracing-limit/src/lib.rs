@@ -1,11 +1,15 @@
 #![deny(warnings)]
 
-use std::fmt;
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt,
+    time::Duration,
+};
 
 use dashmap::DashMap;
 use tracing_core::{
     callsite::Identifier,
-    field::{display, Field, Value, Visit},
+    field::{debug, display, Field, Value, Visit},
     span,
     subscriber::Interest,
     Event, Metadata, Subscriber,
@@ -24,12 +28,14 @@ use mock_instant::global::Instant;
 
 const RATE_LIMIT_FIELD: &str = "internal_log_rate_limit";
 const RATE_LIMIT_SECS_FIELD: &str = "internal_log_rate_secs";
+const RATE_LIMIT_MS_FIELD: &str = "internal_log_rate_ms";
 const MESSAGE_FIELD: &str = "message";
 
-// These fields will cause events to be independently rate limited by the values
-// for these keys
+// By default, these fields will cause events to be independently rate limited by
+// the values for these keys
 const COMPONENT_ID_FIELD: &str = "component_id";
 const VRL_POSITION: &str = "vrl_position";
+const DEFAULT_KEY_FIELDS: &[&str] = &[COMPONENT_ID_FIELD, VRL_POSITION];
 
 #[derive(Eq, PartialEq, Hash, Clone)]
 struct RateKeyIdentifier {
@@ -44,7 +50,9 @@ where
 {
     events: DashMap<RateKeyIdentifier, State>,
     inner: L,
-    internal_log_rate_limit: u64,
+    internal_log_rate_limit: Duration,
+    key_fields: Vec<&'static str>,
+    flush_on_close: bool,
     _subscriber: std::marker::PhantomData<S>,
 }
 
@@ -56,16 +64,39 @@ where
     pub fn new(layer: L) -> Self {
         RateLimitedLayer {
             events: Default::default(),
-            internal_log_rate_limit: 10,
+            internal_log_rate_limit: Duration::from_secs(10),
+            key_fields: DEFAULT_KEY_FIELDS.to_vec(),
+            flush_on_close: false,
             inner: layer,
             _subscriber: std::marker::PhantomData,
         }
     }
 
-    pub fn with_default_limit(mut self, internal_log_rate_limit: u64) -> Self {
+    pub fn with_default_limit(self, internal_log_rate_limit: u64) -> Self {
+        self.with_default_limit_duration(Duration::from_secs(internal_log_rate_limit))
+    }
+
+    /// Sets the default rate limit window, allowing windows shorter than a second.
+    pub fn with_default_limit_duration(mut self, internal_log_rate_limit: Duration) -> Self {
         self.internal_log_rate_limit = internal_log_rate_limit;
         self
     }
+
+    /// Sets the names of the span and event fields whose values separate events from the same
+    /// callsite into independently rate limited groups. Defaults to `component_id` and
+    /// `vrl_position`.
+    pub fn with_key_fields(mut self, key_fields: &[&'static str]) -> Self {
+        self.key_fields = key_fields.to_vec();
+        self
+    }
+
+    /// Emits the suppressed counts of rate limited events when the closest enclosing span carrying
+    /// one of the key fields closes, such as a component's span at shutdown, rather than waiting
+    /// for the event to fire again. Rate limiting of those events starts over afterwards.
+    pub fn with_flush_on_close(mut self, flush_on_close: bool) -> Self {
+        self.flush_on_close = flush_on_close;
+        self
+    }
 }
 
 impl<S, L> Layer<S> for RateLimitedLayer<S, L>
@@ -91,7 +122,7 @@ where
 
             if extensions.get_mut::<RateLimitedSpanKeys>().is_none() {
                 let mut fields = RateLimitedSpanKeys::default();
-                attrs.record(&mut fields);
+                attrs.record(&mut fields.recorder(&self.key_fields));
                 extensions.insert(fields);
             };
         }
@@ -106,11 +137,11 @@ where
 
             match extensions.get_mut::<RateLimitedSpanKeys>() {
                 Some(fields) => {
-                    values.record(fields);
+                    values.record(&mut fields.recorder(&self.key_fields));
                 }
                 None => {
                     let mut fields = RateLimitedSpanKeys::default();
-                    values.record(&mut fields);
+                    values.record(&mut fields.recorder(&self.key_fields));
                     extensions.insert(fields);
                 }
             };
@@ -134,8 +165,8 @@ where
             return self.inner.on_event(event, ctx);
         }
 
-        let limit = match limit_visitor.limit_secs {
-            Some(limit_secs) => limit_secs, // override the cli limit
+        let limit = match limit_visitor.limit_duration {
+            Some(limit_duration) => limit_duration, // override the cli limit
             None => self.internal_log_rate_limit,
         };
 
@@ -144,7 +175,7 @@ where
         // callsite, since multiple copies of the same component could be running, etc.
         let rate_limit_key_values = {
             let mut keys = RateLimitedSpanKeys::default();
-            event.record(&mut keys);
+            event.record(&mut keys.recorder(&self.key_fields));
 
             ctx.lookup_current()
                 .into_iter()
@@ -166,6 +197,29 @@ where
             rate_limit_key_values,
         };
 
+        // Remember which rate limited events occurred within the closest span carrying one of the
+        // key fields, such as a component's span, so that any suppressed counts can be flushed
+        // when that span closes. Short-lived spans without key fields, such as those of a single
+        // request, don't flush, as that would reset rate limiting every time one closes.
+        if self.flush_on_close {
+            let key_span = ctx.event_span(event).and_then(|span| {
+                span.scope().find(|span| {
+                    span.extensions()
+                        .get::<RateLimitedSpanKeys>()
+                        .is_some_and(|keys| !keys.values.is_empty())
+                })
+            });
+            if let Some(span) = key_span {
+                let mut extensions = span.extensions_mut();
+                match extensions.get_mut::<RateLimitedEvents>() {
+                    Some(events) => {
+                        events.0.insert(id.clone());
+                    }
+                    None => extensions.insert(RateLimitedEvents(HashSet::from([id.clone()]))),
+                }
+            }
+        }
+
         let mut state = self.events.entry(id).or_insert_with(|| {
             let mut message_visitor = MessageVisitor::default();
             event.record(&mut message_visitor);
@@ -174,7 +228,7 @@ where
                 .message
                 .unwrap_or_else(|| metadata.name().into());
 
-            State::new(message, limit)
+            State::new(message, limit, metadata)
         });
 
         // Update our suppressed state for this event, and see if we should still be suppressing it.
@@ -226,8 +280,31 @@ where
         self.inner.on_exit(id, ctx);
     }
 
-    #[inline]
+    // emit the suppressed counts of any events rate limited within the closing span, since they
+    // would otherwise only be reported the next time the event fires
     fn on_close(&self, id: span::Id, ctx: Context<'_, S>) {
+        if self.flush_on_close {
+            let events = ctx
+                .span(&id)
+                .and_then(|span| span.extensions_mut().remove::<RateLimitedEvents>());
+
+            for key in events.into_iter().flat_map(|events| events.0) {
+                // Dropping the state means the event starts over if it fires again, so the
+                // suppressed count flushed here is never reported twice.
+                if let Some((_, state)) = self.events.remove(&key) {
+                    if state.count > 1 {
+                        let message = format!(
+                            "Internal log [{}] has been suppressed {} times.",
+                            state.message,
+                            state.count - 1
+                        );
+
+                        self.create_event(&ctx, state.metadata, message, state.limit);
+                    }
+                }
+            }
+        }
+
         self.inner.on_close(id, ctx);
     }
 
@@ -252,27 +329,45 @@ where
         ctx: &Context<S>,
         metadata: &'static Metadata<'static>,
         message: String,
-        rate_limit: u64,
+        rate_limit: Duration,
     ) {
         let fields = metadata.fields();
 
         let message = display(message);
+        let rate_limit_debug = debug(rate_limit);
 
-        if let Some(message_field) = fields.field("message") {
-            let values = [(&message_field, Some(&message as &dyn Value))];
+        let (field, value): (Field, &dyn Value) = match fields.field(MESSAGE_FIELD) {
+            Some(message_field) => (message_field, &message),
+            None => (fields.field(RATE_LIMIT_FIELD).unwrap(), &rate_limit_debug),
+        };
 
-            let valueset = fields.value_set(&values);
-            let event = Event::new(metadata, &valueset);
-            self.inner.on_event(&event, ctx.clone());
-        } else {
-            let values = [(
-                &fields.field(RATE_LIMIT_FIELD).unwrap(),
-                Some(&rate_limit as &dyn Value),
-            )];
-
-            let valueset = fields.value_set(&values);
-            let event = Event::new(metadata, &valueset);
-            self.inner.on_event(&event, ctx.clone());
+        // Report the limit in the unit of the callsite's own limit field, if it has one, so that
+        // sub-second limits aren't truncated to zero seconds.
+        let limit_field = match fields.field(RATE_LIMIT_MS_FIELD) {
+            Some(ms_field) => Some((ms_field, rate_limit.as_millis() as u64)),
+            None => fields
+                .field(RATE_LIMIT_SECS_FIELD)
+                .map(|secs_field| (secs_field, rate_limit.as_secs())),
+        };
+
+        match limit_field {
+            Some((limit_field, limit)) => {
+                let values = [
+                    (&field, Some(value)),
+                    (&limit_field, Some(&limit as &dyn Value)),
+                ];
+
+                let valueset = fields.value_set(&values);
+                let event = Event::new(metadata, &valueset);
+                self.inner.on_event(&event, ctx.clone());
+            }
+            None => {
+                let values = [(&field, Some(value))];
+
+                let valueset = fields.value_set(&values);
+                let event = Event::new(metadata, &valueset);
+                self.inner.on_event(&event, ctx.clone());
+            }
         }
     }
 }
@@ -281,17 +376,19 @@ where
 struct State {
     start: Instant,
     count: u64,
-    limit: u64,
+    limit: Duration,
     message: String,
+    metadata: &'static Metadata<'static>,
 }
 
 impl State {
-    fn new(message: String, limit: u64) -> Self {
+    fn new(message: String, limit: Duration, metadata: &'static Metadata<'static>) -> Self {
         Self {
             start: Instant::now(),
             count: 0,
             limit,
             message,
+            metadata,
         }
     }
 
@@ -307,7 +404,7 @@ impl State {
     }
 
     fn should_limit(&self) -> bool {
-        self.start.elapsed().as_secs() < self.limit
+        self.start.elapsed() < self.limit
     }
 }
 
@@ -348,30 +445,43 @@ impl From<String> for TraceValue {
 /// rate limited separately.
 #[derive(Default, Eq, PartialEq, Hash, Clone)]
 struct RateLimitedSpanKeys {
-    component_id: Option<TraceValue>,
-    vrl_position: Option<TraceValue>,
+    values: BTreeMap<&'static str, TraceValue>,
 }
 
 impl RateLimitedSpanKeys {
-    fn record(&mut self, field: &Field, value: TraceValue) {
-        match field.name() {
-            COMPONENT_ID_FIELD => self.component_id = Some(value),
-            VRL_POSITION => self.vrl_position = Some(value),
-            _ => {}
+    fn recorder<'a>(&'a mut self, key_fields: &'a [&'static str]) -> KeyFieldRecorder<'a> {
+        KeyFieldRecorder {
+            keys: self,
+            key_fields,
         }
     }
 
     fn merge(&mut self, other: &Self) {
-        if let Some(component_id) = &other.component_id {
-            self.component_id = Some(component_id.clone());
-        }
-        if let Some(vrl_position) = &other.vrl_position {
-            self.vrl_position = Some(vrl_position.clone());
+        self.values.extend(
+            other
+                .values
+                .iter()
+                .map(|(name, value)| (*name, value.clone())),
+        );
+    }
+}
+
+/// Records the values of the configured key fields into a `RateLimitedSpanKeys`.
+struct KeyFieldRecorder<'a> {
+    keys: &'a mut RateLimitedSpanKeys,
+    key_fields: &'a [&'static str],
+}
+
+impl KeyFieldRecorder<'_> {
+    fn record(&mut self, field: &Field, value: TraceValue) {
+        let name = field.name();
+        if self.key_fields.contains(&name) {
+            self.keys.values.insert(name, value);
         }
     }
 }
 
-impl Visit for RateLimitedSpanKeys {
+impl Visit for KeyFieldRecorder<'_> {
     fn record_i64(&mut self, field: &Field, value: i64) {
         self.record(field, value.into());
     }
@@ -393,10 +503,26 @@ impl Visit for RateLimitedSpanKeys {
     }
 }
 
+/// RateLimitedEvents tracks the rate limited events that occurred within a span carrying key
+/// fields, so their suppressed counts can be emitted when the span closes.
+struct RateLimitedEvents(HashSet<RateKeyIdentifier>);
+
 #[derive(Default)]
 struct LimitVisitor {
     pub limit: Option<bool>,
-    pub limit_secs: Option<u64>,
+    pub limit_duration: Option<Duration>,
+}
+
+impl LimitVisitor {
+    fn record_limit(&mut self, field: &Field, value: u64) {
+        let limit_duration = match field.name() {
+            RATE_LIMIT_SECS_FIELD => Duration::from_secs(value),
+            RATE_LIMIT_MS_FIELD => Duration::from_millis(value),
+            _ => return,
+        };
+        self.limit = Some(true); // limit if we have this field
+        self.limit_duration = Some(limit_duration); // override the cli passed limit
+    }
 }
 
 impl Visit for LimitVisitor {
@@ -407,17 +533,11 @@ impl Visit for LimitVisitor {
     }
 
     fn record_i64(&mut self, field: &Field, value: i64) {
-        if field.name() == RATE_LIMIT_SECS_FIELD {
-            self.limit = Some(true); // limit if we have this field
-            self.limit_secs = Some(u64::try_from(value).unwrap_or_default()); // override the cli passed limit
-        }
+        self.record_limit(field, u64::try_from(value).unwrap_or_default());
     }
 
     fn record_u64(&mut self, field: &Field, value: u64) {
-        if field.name() == RATE_LIMIT_SECS_FIELD {
-            self.limit = Some(true); // limit if we have this field
-            self.limit_secs = Some(value); // override the cli passed limit
-        }
+        self.record_limit(field, value);
     }
 
     fn record_debug(&mut self, _field: &Field, _value: &dyn fmt::Debug) {}
@@ -457,20 +577,46 @@ mod test {
     #[derive(Default)]
     struct RecordingLayer<S> {
         events: Arc<Mutex<Vec<String>>>,
+        limits: Arc<Mutex<Vec<u64>>>,
 
         _subscriber: std::marker::PhantomData<S>,
     }
 
     impl<S> RecordingLayer<S> {
         fn new(events: Arc<Mutex<Vec<String>>>) -> Self {
+            Self::with_limits(events, Default::default())
+        }
+
+        /// Also records the `internal_log_rate_ms` value of every event that has one.
+        fn with_limits(events: Arc<Mutex<Vec<String>>>, limits: Arc<Mutex<Vec<u64>>>) -> Self {
             RecordingLayer {
                 events,
+                limits,
 
                 _subscriber: std::marker::PhantomData,
             }
         }
     }
 
+    #[derive(Default)]
+    struct LimitMsVisitor {
+        limit: Option<u64>,
+    }
+
+    impl Visit for LimitMsVisitor {
+        fn record_i64(&mut self, field: &Field, value: i64) {
+            self.record_u64(field, value as u64);
+        }
+
+        fn record_u64(&mut self, field: &Field, value: u64) {
+            if field.name() == RATE_LIMIT_MS_FIELD {
+                self.limit = Some(value);
+            }
+        }
+
+        fn record_debug(&mut self, _field: &Field, _value: &dyn fmt::Debug) {}
+    }
+
     impl<S> Layer<S> for RecordingLayer<S>
     where
         S: Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
@@ -489,6 +635,12 @@ mod test {
 
             let mut events = self.events.lock().unwrap();
             events.push(visitor.message.unwrap_or_default());
+
+            let mut limit_visitor = LimitMsVisitor::default();
+            event.record(&mut limit_visitor);
+            if let Some(limit) = limit_visitor.limit {
+                self.limits.lock().unwrap().push(limit);
+            }
         }
     }
 
@@ -562,6 +714,159 @@ mod test {
         );
     }
 
+    #[test]
+    fn sub_second_default_rate_limit() {
+        let events: Arc<Mutex<Vec<String>>> = Default::default();
+
+        let recorder = RecordingLayer::new(Arc::clone(&events));
+        let sub = tracing_subscriber::registry::Registry::default().with(
+            RateLimitedLayer::new(recorder).with_default_limit_duration(Duration::from_millis(250)),
+        );
+        tracing::subscriber::with_default(sub, || {
+            for _ in 0..11 {
+                info!(message = "Hello world!", internal_log_rate_limit = true);
+                MockClock::advance(Duration::from_millis(50));
+            }
+        });
+
+        let events = events.lock().unwrap();
+
+        assert_eq!(
+            *events,
+            vec![
+                "Hello world!",
+                "Internal log [Hello world!] is being suppressed to avoid flooding.",
+                "Internal log [Hello world!] has been suppressed 4 times.",
+                "Hello world!",
+                "Internal log [Hello world!] is being suppressed to avoid flooding.",
+                "Internal log [Hello world!] has been suppressed 4 times.",
+                "Hello world!",
+            ]
+            .into_iter()
+            .map(std::borrow::ToOwned::to_owned)
+            .collect::<Vec<String>>()
+        );
+    }
+
+    #[test]
+    fn override_sub_second_rate_limit_at_callsite() {
+        let events: Arc<Mutex<Vec<String>>> = Default::default();
+        let limits: Arc<Mutex<Vec<u64>>> = Default::default();
+
+        let recorder = RecordingLayer::with_limits(Arc::clone(&events), Arc::clone(&limits));
+        let sub = tracing_subscriber::registry::Registry::default()
+            .with(RateLimitedLayer::new(recorder).with_default_limit(100));
+        tracing::subscriber::with_default(sub, || {
+            for _ in 0..11 {
+                info!(
+                    message = "Hello world!",
+                    internal_log_rate_limit = true,
+                    internal_log_rate_ms = 250
+                );
+                MockClock::advance(Duration::from_millis(50));
+            }
+        });
+
+        let events = events.lock().unwrap();
+
+        assert_eq!(
+            *events,
+            vec![
+                "Hello world!",
+                "Internal log [Hello world!] is being suppressed to avoid flooding.",
+                "Internal log [Hello world!] has been suppressed 4 times.",
+                "Hello world!",
+                "Internal log [Hello world!] is being suppressed to avoid flooding.",
+                "Internal log [Hello world!] has been suppressed 4 times.",
+                "Hello world!",
+            ]
+            .into_iter()
+            .map(std::borrow::ToOwned::to_owned)
+            .collect::<Vec<String>>()
+        );
+
+        // The suppression events report the sub-second limit too, rather than truncating it to
+        // zero seconds.
+        assert_eq!(*limits.lock().unwrap(), vec![250; 7]);
+    }
+
+    #[test]
+    fn emit_suppressed_count_on_span_close() {
+        let events: Arc<Mutex<Vec<String>>> = Default::default();
+
+        let recorder = RecordingLayer::new(Arc::clone(&events));
+        let sub = tracing_subscriber::registry::Registry::default().with(
+            RateLimitedLayer::new(recorder)
+                .with_default_limit(1)
+                .with_flush_on_close(true),
+        );
+        tracing::subscriber::with_default(sub, || {
+            for _ in 0..2 {
+                let span = info_span!("span", component_id = "foo");
+                let _enter = span.enter();
+                for _ in 0..6 {
+                    info!(message = "Hello world!", internal_log_rate_limit = true);
+                    MockClock::advance(Duration::from_millis(10));
+                }
+            }
+        });
+
+        let events = events.lock().unwrap();
+
+        assert_eq!(
+            *events,
+            vec![
+                "Hello world!",
+                "Internal log [Hello world!] is being suppressed to avoid flooding.",
+                "Internal log [Hello world!] has been suppressed 5 times.",
+                "Hello world!",
+                "Internal log [Hello world!] is being suppressed to avoid flooding.",
+                "Internal log [Hello world!] has been suppressed 5 times.",
+            ]
+            .into_iter()
+            .map(std::borrow::ToOwned::to_owned)
+            .collect::<Vec<String>>()
+        );
+    }
+
+    #[test]
+    fn flush_on_close_ignores_spans_without_key_fields() {
+        let events: Arc<Mutex<Vec<String>>> = Default::default();
+
+        let recorder = RecordingLayer::new(Arc::clone(&events));
+        let sub = tracing_subscriber::registry::Registry::default().with(
+            RateLimitedLayer::new(recorder)
+                .with_default_limit(1)
+                .with_flush_on_close(true),
+        );
+        tracing::subscriber::with_default(sub, || {
+            let component = info_span!("component", component_id = "foo");
+            let _component = component.enter();
+            for _ in 0..6 {
+                let request = info_span!("request", request_id = 1);
+                let _request = request.enter();
+                info!(message = "Hello world!", internal_log_rate_limit = true);
+                MockClock::advance(Duration::from_millis(10));
+            }
+            drop(_component);
+            drop(component);
+        });
+
+        let events = events.lock().unwrap();
+
+        assert_eq!(
+            *events,
+            vec![
+                "Hello world!",
+                "Internal log [Hello world!] is being suppressed to avoid flooding.",
+                "Internal log [Hello world!] has been suppressed 5 times.",
+            ]
+            .into_iter()
+            .map(std::borrow::ToOwned::to_owned)
+            .collect::<Vec<String>>()
+        );
+    }
+
     #[test]
     fn rate_limit_by_span_key() {
         let events: Arc<Mutex<Vec<String>>> = Default::default();
@@ -627,6 +932,56 @@ mod test {
         );
     }
 
+    #[test]
+    fn rate_limit_by_custom_key_field() {
+        let events: Arc<Mutex<Vec<String>>> = Default::default();
+
+        let recorder = RecordingLayer::new(Arc::clone(&events));
+        let sub = tracing_subscriber::registry::Registry::default().with(
+            RateLimitedLayer::new(recorder)
+                .with_default_limit(1)
+                .with_key_fields(&["component_id", "endpoint"]),
+        );
+        tracing::subscriber::with_default(sub, || {
+            for _ in 0..21 {
+                for endpoint in &["foo", "bar"] {
+                    let span = info_span!("span", component_id = "sink", endpoint = &endpoint);
+                    let _enter = span.enter();
+                    info!(
+                        message = format!("Hello {}!", endpoint).as_str(),
+                        internal_log_rate_limit = true
+                    );
+                }
+                MockClock::advance(Duration::from_millis(100));
+            }
+        });
+
+        let events = events.lock().unwrap();
+
+        assert_eq!(
+            *events,
+            vec![
+                "Hello foo!",
+                "Hello bar!",
+                "Internal log [Hello foo!] is being suppressed to avoid flooding.",
+                "Internal log [Hello bar!] is being suppressed to avoid flooding.",
+                "Internal log [Hello foo!] has been suppressed 9 times.",
+                "Hello foo!",
+                "Internal log [Hello bar!] has been suppressed 9 times.",
+                "Hello bar!",
+                "Internal log [Hello foo!] is being suppressed to avoid flooding.",
+                "Internal log [Hello bar!] is being suppressed to avoid flooding.",
+                "Internal log [Hello foo!] has been suppressed 9 times.",
+                "Hello foo!",
+                "Internal log [Hello bar!] has been suppressed 9 times.",
+                "Hello bar!",
+            ]
+            .into_iter()
+            .map(std::borrow::ToOwned::to_owned)
+            .collect::<Vec<String>>()
+        );
+    }
+
     #[test]
     fn rate_limit_by_event_key() {
         let events: Arc<Mutex<Vec<String>>> = Default::default();
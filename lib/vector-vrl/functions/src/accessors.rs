@@ -0,0 +1,230 @@
+use vrl::prelude::*;
+
+/// Returns the element of an array, or the character of a string, at `index`.
+///
+/// Negative indices count from the end, and out-of-range indices return `null`.
+fn nth(value: Value, index: i64) -> Resolved {
+    let resolve = |len: usize| {
+        let index = if index < 0 {
+            index.checked_add(len as i64)?
+        } else {
+            index
+        };
+        usize::try_from(index).ok().filter(|index| *index < len)
+    };
+
+    Ok(match value {
+        Value::Array(mut array) => match resolve(array.len()) {
+            Some(index) => array.swap_remove(index),
+            None => Value::Null,
+        },
+        Value::Bytes(bytes) => {
+            let string = String::from_utf8_lossy(&bytes);
+            let chars = string.chars().collect::<Vec<_>>();
+            match resolve(chars.len()) {
+                Some(index) => chars[index].to_string().into(),
+                None => Value::Null,
+            }
+        }
+        value => {
+            return Err(ValueError::Expected {
+                got: value.kind(),
+                expected: Kind::array(Collection::any()) | Kind::bytes(),
+            }
+            .into())
+        }
+    })
+}
+
+const VALUE: Parameter = Parameter {
+    keyword: "value",
+    kind: kind::ARRAY | kind::BYTES,
+    required: true,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct First;
+
+impl Function for First {
+    fn identifier(&self) -> &'static str {
+        "first"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[VALUE]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "first element of an array",
+                source: r#"first(["a", "b", "c"])"#,
+                result: Ok("a"),
+            },
+            Example {
+                title: "first character of a string",
+                source: r#"first("héllo")"#,
+                result: Ok("h"),
+            },
+            Example {
+                title: "empty array",
+                source: r#"first([])"#,
+                result: Ok("null"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(NthFn {
+            value,
+            index: Index::Fixed(0),
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Last;
+
+impl Function for Last {
+    fn identifier(&self) -> &'static str {
+        "last"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[VALUE]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "last element of an array",
+                source: r#"last(["a", "b", "c"])"#,
+                result: Ok("c"),
+            },
+            Example {
+                title: "last character of a string",
+                source: r#"last("héllo")"#,
+                result: Ok("o"),
+            },
+            Example {
+                title: "empty string",
+                source: r#"last("")"#,
+                result: Ok("null"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(NthFn {
+            value,
+            index: Index::Fixed(-1),
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Nth;
+
+impl Function for Nth {
+    fn identifier(&self) -> &'static str {
+        "nth"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            VALUE,
+            Parameter {
+                keyword: "n",
+                kind: kind::INTEGER,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "element at an index",
+                source: r#"nth(["a", "b", "c"], 1)"#,
+                result: Ok("b"),
+            },
+            Example {
+                title: "negative index counts from the end",
+                source: r#"nth(["a", "b", "c"], -3)"#,
+                result: Ok("a"),
+            },
+            Example {
+                title: "out of range",
+                source: r#"nth(["a", "b", "c"], -4)"#,
+                result: Ok("null"),
+            },
+            Example {
+                title: "character at an index",
+                source: r#"nth("héllo", 1)"#,
+                result: Ok("é"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let index = Index::Dynamic(arguments.required("n"));
+
+        Ok(NthFn { value, index }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Index {
+    Fixed(i64),
+    Dynamic(Box<dyn Expression>),
+}
+
+#[derive(Debug, Clone)]
+struct NthFn {
+    value: Box<dyn Expression>,
+    index: Index,
+}
+
+impl FunctionExpression for NthFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let index = match &self.index {
+            Index::Fixed(index) => *index,
+            Index::Dynamic(index) => index.resolve(ctx)?.try_integer()?,
+        };
+
+        nth(value, index)
+    }
+
+    fn type_def(&self, state: &TypeState) -> TypeDef {
+        let kind = if self.value.type_def(state).is_bytes() {
+            Kind::bytes()
+        } else {
+            Kind::any()
+        };
+
+        TypeDef::from(kind.or_null()).infallible()
+    }
+}
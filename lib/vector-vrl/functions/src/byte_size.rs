@@ -0,0 +1,102 @@
+use vrl::prelude::*;
+
+/// Estimates the number of bytes `value` occupies when serialized as JSON.
+///
+/// The estimate is exact for strings without characters that need escaping, integers, booleans,
+/// null and collections thereof. Floats and timestamps are approximated by their display length.
+fn estimate(value: &Value) -> usize {
+    match value {
+        Value::Bytes(bytes) => bytes.len() + 2,
+        Value::Regex(regex) => regex.as_str().len() + 2,
+        Value::Integer(integer) => integer.to_string().len(),
+        Value::Float(float) => float.to_string().len(),
+        Value::Boolean(true) => 4,
+        Value::Boolean(false) => 5,
+        Value::Timestamp(_) => 32,
+        Value::Null => 4,
+        Value::Array(array) => {
+            2 + array.iter().map(estimate).sum::<usize>() + array.len().saturating_sub(1)
+        }
+        Value::Object(object) => {
+            2 + object
+                .iter()
+                .map(|(key, value)| key.as_str().len() + 3 + estimate(value))
+                .sum::<usize>()
+                + object.len().saturating_sub(1)
+        }
+    }
+}
+
+fn byte_size(value: &Value) -> Resolved {
+    Ok(Value::Integer(estimate(value) as i64))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ByteSize;
+
+impl Function for ByteSize {
+    fn identifier(&self) -> &'static str {
+        "byte_size"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::ANY,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "string",
+                source: r#"byte_size("hello")"#,
+                result: Ok("7"),
+            },
+            Example {
+                title: "object",
+                source: r#"byte_size({"a": 1, "b": [true, null]})"#,
+                result: Ok("23"),
+            },
+            Example {
+                title: "larger object",
+                source: r#"byte_size({"a": 1, "b": [true, null], "c": "text"}) > byte_size({"a": 1, "b": [true, null]})"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "deeper nesting",
+                source: r#"byte_size({"a": {"b": {"c": 1}}}) > byte_size({"a": {"b": 1}})"#,
+                result: Ok("true"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(ByteSizeFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ByteSizeFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ByteSizeFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        byte_size(&value)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::integer().infallible()
+    }
+}
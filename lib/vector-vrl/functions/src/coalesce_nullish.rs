@@ -0,0 +1,98 @@
+use vrl::prelude::*;
+
+/// Mirrors the stdlib `is_nullish` semantics, additionally treating any of `extra` as nullish.
+fn is_nullish(value: &Value, extra: &[Value]) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Bytes(bytes) => {
+            let string = String::from_utf8_lossy(bytes);
+            string == "-" || string.chars().all(char::is_whitespace) || extra.contains(value)
+        }
+        value => extra.contains(value),
+    }
+}
+
+fn coalesce_nullish(values: Vec<Value>, extra: &[Value]) -> Resolved {
+    Ok(values
+        .into_iter()
+        .find(|value| !is_nullish(value, extra))
+        .unwrap_or(Value::Null))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct CoalesceNullish;
+
+impl Function for CoalesceNullish {
+    fn identifier(&self) -> &'static str {
+        "coalesce_nullish"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "values",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "extra",
+                kind: kind::ARRAY,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "first non-nullish value",
+                source: r#"coalesce_nullish([null, "", "-", "  ", "value", "other"])"#,
+                result: Ok("value"),
+            },
+            Example {
+                title: "custom nullish values",
+                source: r#"coalesce_nullish(["N/A", "null", "value"], extra: ["N/A", "null"])"#,
+                result: Ok("value"),
+            },
+            Example {
+                title: "all values nullish",
+                source: r#"coalesce_nullish([null, "-"])"#,
+                result: Ok("null"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let values = arguments.required("values");
+        let extra = arguments.optional("extra");
+
+        Ok(CoalesceNullishFn { values, extra }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CoalesceNullishFn {
+    values: Box<dyn Expression>,
+    extra: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for CoalesceNullishFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let values = self.values.resolve(ctx)?.try_array()?;
+        let extra = match &self.extra {
+            Some(extra) => extra.resolve(ctx)?.try_array()?,
+            None => Vec::new(),
+        };
+
+        coalesce_nullish(values, &extra)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::any().infallible()
+    }
+}
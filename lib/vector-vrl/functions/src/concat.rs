@@ -0,0 +1,87 @@
+use vrl::prelude::*;
+
+fn concat(values: Vec<Value>) -> Value {
+    let mut result = Vec::with_capacity(values.len());
+    for value in values {
+        match value {
+            Value::Array(array) => result.extend(array),
+            value => result.push(value),
+        }
+    }
+    Value::Array(result)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Concat;
+
+impl Function for Concat {
+    fn identifier(&self) -> &'static str {
+        "concat"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "values",
+            kind: kind::ARRAY,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "concatenate arrays",
+                source: r#"concat([[1, 2], [3], ["a", "b"]])"#,
+                result: Ok(r#"[1, 2, 3, "a", "b"]"#),
+            },
+            Example {
+                title: "mixed arrays and scalars",
+                source: r#"concat([[1, 2], 3, "a", null, [true]])"#,
+                result: Ok(r#"[1, 2, 3, "a", null, true]"#),
+            },
+            Example {
+                title: "only one level is flattened",
+                source: r#"concat([[1, [2, 3]], [[4]]])"#,
+                result: Ok("[1, [2, 3], [4]]"),
+            },
+            Example {
+                title: "objects are kept whole",
+                source: r#"concat([{"a": 1}, [{"b": 2}]])"#,
+                result: Ok(r#"[{"a": 1}, {"b": 2}]"#),
+            },
+            Example {
+                title: "empty arrays",
+                source: r#"concat([[], [], []])"#,
+                result: Ok("[]"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let values = arguments.required("values");
+
+        Ok(ConcatFn { values }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ConcatFn {
+    values: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ConcatFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let values = self.values.resolve(ctx)?.try_array()?;
+
+        Ok(concat(values))
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::array(Collection::any())
+    }
+}
@@ -0,0 +1,144 @@
+use crc::{Crc, CRC_32_ISCSI, CRC_32_ISO_HDLC};
+use vrl::prelude::*;
+
+/// The CRC-32 used by zlib, gzip, ZIP, and Ethernet.
+const CRC32: Crc<u32> = Crc::<u32>::new(&CRC_32_ISO_HDLC);
+
+/// The Castagnoli CRC-32 used by iSCSI, ext4, and Kafka record batches.
+const CRC32C: Crc<u32> = Crc::<u32>::new(&CRC_32_ISCSI);
+
+#[derive(Clone, Copy, Debug)]
+enum Algorithm {
+    Crc32,
+    Crc32c,
+}
+
+impl Algorithm {
+    fn checksum(self, bytes: &[u8]) -> u32 {
+        match self {
+            Self::Crc32 => CRC32.checksum(bytes),
+            Self::Crc32c => CRC32C.checksum(bytes),
+        }
+    }
+}
+
+const PARAMETERS: &[Parameter] = &[Parameter {
+    keyword: "value",
+    kind: kind::BYTES,
+    required: true,
+}];
+
+#[derive(Clone, Copy, Debug)]
+pub struct Crc32;
+
+impl Function for Crc32 {
+    fn identifier(&self) -> &'static str {
+        "crc32"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        PARAMETERS
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "check value",
+                source: r#"crc32("123456789")"#,
+                result: Ok("3421780262"),
+            },
+            Example {
+                title: "sentence",
+                source: r#"crc32("The quick brown fox jumps over the lazy dog")"#,
+                result: Ok("1095738169"),
+            },
+            Example {
+                title: "empty string",
+                source: r#"crc32("")"#,
+                result: Ok("0"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Crc32Fn {
+            value,
+            algorithm: Algorithm::Crc32,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Crc32c;
+
+impl Function for Crc32c {
+    fn identifier(&self) -> &'static str {
+        "crc32c"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        PARAMETERS
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "check value",
+                source: r#"crc32c("123456789")"#,
+                result: Ok("3808858755"),
+            },
+            Example {
+                title: "sentence",
+                source: r#"crc32c("The quick brown fox jumps over the lazy dog")"#,
+                result: Ok("576848900"),
+            },
+            Example {
+                title: "empty string",
+                source: r#"crc32c("")"#,
+                result: Ok("0"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Crc32Fn {
+            value,
+            algorithm: Algorithm::Crc32c,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Crc32Fn {
+    value: Box<dyn Expression>,
+    algorithm: Algorithm,
+}
+
+impl FunctionExpression for Crc32Fn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let value = value.try_bytes()?;
+
+        Ok(Value::Integer(self.algorithm.checksum(&value).into()))
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::integer().infallible()
+    }
+}
@@ -0,0 +1,195 @@
+use vrl::prelude::*;
+
+fn dedupe_consecutive(value: Vec<Value>) -> Value {
+    let mut result: Vec<Value> = Vec::with_capacity(value.len());
+    for value in value {
+        if result.last() != Some(&value) {
+            result.push(value);
+        }
+    }
+
+    Value::Array(result)
+}
+
+fn dedupe_consecutive_by<T>(
+    value: Vec<Value>,
+    ctx: &mut Context,
+    runner: closure::Runner<T>,
+) -> Resolved
+where
+    T: Fn(&mut Context) -> Resolved,
+{
+    let mut result = Vec::with_capacity(value.len());
+    let mut last_key = None;
+
+    for (index, value) in value.into_iter().enumerate() {
+        let key = runner.run_index_value(ctx, index, &value)?;
+        if last_key.as_ref() != Some(&key) {
+            result.push(value);
+        }
+        last_key = Some(key);
+    }
+
+    Ok(Value::Array(result))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct DedupeConsecutive;
+
+impl Function for DedupeConsecutive {
+    fn identifier(&self) -> &'static str {
+        "dedupe_consecutive"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::ARRAY,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "adjacent duplicates",
+                source: r#"dedupe_consecutive(["a", "a", "b", "b", "b", "c"])"#,
+                result: Ok(r#"["a", "b", "c"]"#),
+            },
+            Example {
+                title: "non-adjacent duplicates are kept",
+                source: r#"dedupe_consecutive(["a", "b", "a", "a", "b"])"#,
+                result: Ok(r#"["a", "b", "a", "b"]"#),
+            },
+            Example {
+                title: "empty array",
+                source: "dedupe_consecutive([])",
+                result: Ok("[]"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(DedupeConsecutiveFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct DedupeConsecutiveFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for DedupeConsecutiveFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_array()?;
+
+        Ok(dedupe_consecutive(value))
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::array(Collection::any()).infallible()
+    }
+}
+
+/// Like `dedupe_consecutive`, comparing the keys returned by a closure rather than the elements
+/// themselves. VRL closures can't be optional, so this is a separate function.
+#[derive(Clone, Copy, Debug)]
+pub struct DedupeConsecutiveBy;
+
+impl Function for DedupeConsecutiveBy {
+    fn identifier(&self) -> &'static str {
+        "dedupe_consecutive_by"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::ARRAY,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "adjacent duplicate keys",
+                source: r#"dedupe_consecutive_by([{"msg": "a", "n": 1}, {"msg": "a", "n": 2}, {"msg": "b", "n": 3}]) -> |_index, value| { value.msg }"#,
+                result: Ok(r#"[{"msg": "a", "n": 1}, {"msg": "b", "n": 3}]"#),
+            },
+            Example {
+                title: "non-adjacent duplicate keys are kept",
+                source: r#"dedupe_consecutive_by(["a", "A", "b", "a"]) -> |_index, value| { downcase(value) }"#,
+                result: Ok(r#"["a", "b", "a"]"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let closure = arguments.required_closure()?;
+
+        Ok(DedupeConsecutiveByFn { value, closure }.as_expr())
+    }
+
+    fn closure(&self) -> Option<closure::Definition> {
+        use closure::{Definition, Input, Output, Variable, VariableKind};
+
+        Some(Definition {
+            inputs: vec![Input {
+                parameter_keyword: "value",
+                kind: Kind::array(Collection::any()),
+                variables: vec![
+                    Variable {
+                        kind: VariableKind::TargetInnerKey,
+                    },
+                    Variable {
+                        kind: VariableKind::TargetInnerValue,
+                    },
+                ],
+                output: Output::Kind(Kind::any()),
+                example: Example {
+                    title: "adjacent duplicate keys",
+                    source: r#"dedupe_consecutive_by(["a", "A", "b"]) -> |_index, value| { downcase(value) }"#,
+                    result: Ok(r#"["a", "b"]"#),
+                },
+            }],
+            is_iterator: true,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct DedupeConsecutiveByFn {
+    value: Box<dyn Expression>,
+    closure: FunctionClosure,
+}
+
+impl FunctionExpression for DedupeConsecutiveByFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_array()?;
+        let FunctionClosure {
+            variables,
+            block,
+            block_type_def: _,
+        } = &self.closure;
+        let runner = closure::Runner::new(variables, |ctx| block.resolve(ctx));
+
+        dedupe_consecutive_by(value, ctx, runner)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::array(Collection::any())
+    }
+}
@@ -0,0 +1,134 @@
+use vrl::prelude::*;
+
+fn default_values(
+    mut value: ObjectMap,
+    defaults: ObjectMap,
+    recursive: bool,
+    replace_null: bool,
+) -> ObjectMap {
+    for (key, default) in defaults {
+        match value.get_mut(&key) {
+            None => {
+                value.insert(key, default);
+            }
+            Some(existing) => match (existing, default) {
+                (Value::Object(existing), Value::Object(default)) if recursive => {
+                    *existing =
+                        default_values(std::mem::take(existing), default, recursive, replace_null);
+                }
+                (existing, default) if replace_null && existing.is_null() => *existing = default,
+                _ => {}
+            },
+        }
+    }
+
+    value
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct DefaultValues;
+
+impl Function for DefaultValues {
+    fn identifier(&self) -> &'static str {
+        "default_values"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::OBJECT,
+                required: true,
+            },
+            Parameter {
+                keyword: "defaults",
+                kind: kind::OBJECT,
+                required: true,
+            },
+            Parameter {
+                keyword: "recursive",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+            Parameter {
+                keyword: "replace_null",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "fill in missing keys",
+                source: r#"default_values({"level": "warn", "host": null}, {"level": "info", "host": "localhost", "env": "prod"})"#,
+                result: Ok(r#"{"level": "warn", "host": null, "env": "prod"}"#),
+            },
+            Example {
+                title: "replace present null keys",
+                source: r#"default_values({"level": "warn", "host": null}, {"level": "info", "host": "localhost"}, replace_null: true)"#,
+                result: Ok(r#"{"level": "warn", "host": "localhost"}"#),
+            },
+            Example {
+                title: "fill in nested keys",
+                source: r#"default_values({"http": {"method": "GET"}}, {"http": {"method": "POST", "version": "1.1"}}, recursive: true)"#,
+                result: Ok(r#"{"http": {"method": "GET", "version": "1.1"}}"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let defaults = arguments.required("defaults");
+        let recursive = arguments.optional("recursive");
+        let replace_null = arguments.optional("replace_null");
+
+        Ok(DefaultValuesFn {
+            value,
+            defaults,
+            recursive,
+            replace_null,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct DefaultValuesFn {
+    value: Box<dyn Expression>,
+    defaults: Box<dyn Expression>,
+    recursive: Option<Box<dyn Expression>>,
+    replace_null: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for DefaultValuesFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_object()?;
+        let defaults = self.defaults.resolve(ctx)?.try_object()?;
+        let recursive = match &self.recursive {
+            Some(recursive) => recursive.resolve(ctx)?.try_boolean()?,
+            None => false,
+        };
+        let replace_null = match &self.replace_null {
+            Some(replace_null) => replace_null.resolve(ctx)?.try_boolean()?,
+            None => false,
+        };
+
+        Ok(Value::Object(default_values(
+            value,
+            defaults,
+            recursive,
+            replace_null,
+        )))
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::object(Collection::any()).infallible()
+    }
+}
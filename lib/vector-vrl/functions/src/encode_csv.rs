@@ -0,0 +1,138 @@
+use chrono::SecondsFormat;
+use vrl::prelude::*;
+
+/// Stringifies a field the same way `to_string` does.
+fn field_string(value: Value) -> Result<String, ExpressionError> {
+    Ok(match value {
+        Value::Bytes(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+        Value::Integer(v) => v.to_string(),
+        Value::Float(v) => v.to_string(),
+        Value::Boolean(v) => v.to_string(),
+        Value::Timestamp(v) => v.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+        Value::Null => String::new(),
+        v => return Err(format!("unable to coerce {} into string", v.kind_str()).into()),
+    })
+}
+
+fn encode_csv(value: Vec<Value>, delimiter: &str) -> Resolved {
+    let mut chars = delimiter.chars();
+    let delimiter = match (chars.next(), chars.next()) {
+        (Some(delimiter), None) => delimiter,
+        _ => return Err("delimiter must be a single character".into()),
+    };
+
+    let mut row = String::new();
+    for (index, value) in value.into_iter().enumerate() {
+        if index > 0 {
+            row.push(delimiter);
+        }
+
+        // RFC 4180: fields containing the delimiter, double quotes, or line breaks are quoted,
+        // with any double quotes escaped by doubling them.
+        let field = field_string(value)?;
+        if field.contains([delimiter, '"', '\n', '\r']) {
+            row.push('"');
+            row.push_str(&field.replace('"', "\"\""));
+            row.push('"');
+        } else {
+            row.push_str(&field);
+        }
+    }
+
+    Ok(row.into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct EncodeCsv;
+
+impl Function for EncodeCsv {
+    fn identifier(&self) -> &'static str {
+        "encode_csv"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "delimiter",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "plain fields",
+                source: r#"encode_csv!(["foo", 1, true, null, 1.5])"#,
+                result: Ok(r#""foo,1,true,,1.5""#),
+            },
+            Example {
+                title: "embedded quotes",
+                source: r#"encode_csv!(["say \"hi\"", "bar"])"#,
+                result: Ok(r#""\"say \"\"hi\"\"\",bar""#),
+            },
+            Example {
+                title: "embedded delimiter and newline",
+                source: r#"encode_csv!(["a,b", "c\nd", "e"])"#,
+                result: Ok(r#""\"a,b\",\"c\nd\",e""#),
+            },
+            Example {
+                title: "custom delimiter",
+                source: r#"encode_csv!(["a,b", "c|d"], delimiter: "|")"#,
+                result: Ok(r#""a,b|\"c|d\"""#),
+            },
+            Example {
+                title: "empty array",
+                source: r#"encode_csv!([])"#,
+                result: Ok(r#""""#),
+            },
+            Example {
+                title: "nested field",
+                source: r#"encode_csv!(["a", ["b"]])"#,
+                result: Err(
+                    r#"function call error for "encode_csv" at (0:25): unable to coerce array into string"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let delimiter = arguments.optional("delimiter");
+
+        Ok(EncodeCsvFn { value, delimiter }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct EncodeCsvFn {
+    value: Box<dyn Expression>,
+    delimiter: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for EncodeCsvFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_array()?;
+        let delimiter = match &self.delimiter {
+            Some(delimiter) => delimiter.resolve(ctx)?.try_bytes_utf8_lossy()?.into_owned(),
+            None => ",".to_owned(),
+        };
+
+        encode_csv(value, &delimiter)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::bytes().fallible()
+    }
+}
@@ -0,0 +1,267 @@
+use chrono::SecondsFormat;
+use vrl::prelude::*;
+
+/// Checks for a valid XML element or attribute name, restricted to ASCII.
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == ':')
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | ':' | '-' | '.'))
+}
+
+fn check_name(name: &str) -> Result<(), ExpressionError> {
+    if is_valid_name(name) {
+        Ok(())
+    } else {
+        Err(format!("invalid XML name {name:?}").into())
+    }
+}
+
+fn escape(value: &str, out: &mut String) {
+    for c in value.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+}
+
+/// Stringifies a scalar the same way `to_string` does.
+fn scalar_string(value: &Value) -> Result<String, ExpressionError> {
+    Ok(match value {
+        Value::Bytes(bytes) => String::from_utf8_lossy(bytes).into_owned(),
+        Value::Integer(v) => v.to_string(),
+        Value::Float(v) => v.to_string(),
+        Value::Boolean(v) => v.to_string(),
+        Value::Timestamp(v) => v.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+        Value::Null => String::new(),
+        v => return Err(format!("unable to coerce {} into string", v.kind_str()).into()),
+    })
+}
+
+struct Encoder<'a> {
+    attr_prefix: &'a str,
+    text_key: &'a str,
+    out: String,
+}
+
+impl Encoder<'_> {
+    /// Writes `value` as an element named `name`. Arrays repeat the element once per item.
+    fn element(&mut self, name: &str, value: &Value) -> Result<(), ExpressionError> {
+        check_name(name)?;
+
+        match value {
+            Value::Array(items) => {
+                for item in items {
+                    if matches!(item, Value::Array(_)) {
+                        return Err(format!("nested array in element {name:?}").into());
+                    }
+                    self.element(name, item)?;
+                }
+            }
+            Value::Object(object) => self.object(name, object)?,
+            Value::Null => {
+                self.out.push('<');
+                self.out.push_str(name);
+                self.out.push_str("/>");
+            }
+            value => {
+                self.out.push('<');
+                self.out.push_str(name);
+                self.out.push('>');
+                escape(&scalar_string(value)?, &mut self.out);
+                self.close(name);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes an object as an element, turning prefixed keys into attributes and the text key
+    /// into the element's text.
+    fn object(&mut self, name: &str, object: &ObjectMap) -> Result<(), ExpressionError> {
+        let mut text = None;
+        let mut children = Vec::new();
+
+        self.out.push('<');
+        self.out.push_str(name);
+        for (key, value) in object {
+            if let Some(attribute) = key.strip_prefix(self.attr_prefix) {
+                check_name(attribute)?;
+                self.out.push(' ');
+                self.out.push_str(attribute);
+                self.out.push_str("=\"");
+                escape(&scalar_string(value)?, &mut self.out);
+                self.out.push('"');
+            } else if key.as_str() == self.text_key {
+                text = Some(scalar_string(value)?);
+            } else {
+                children.push((key, value));
+            }
+        }
+
+        if text.is_none() && children.is_empty() {
+            self.out.push_str("/>");
+            return Ok(());
+        }
+
+        self.out.push('>');
+        if let Some(text) = text {
+            escape(&text, &mut self.out);
+        }
+        for (key, value) in children {
+            self.element(key, value)?;
+        }
+        self.close(name);
+
+        Ok(())
+    }
+
+    fn close(&mut self, name: &str) {
+        self.out.push_str("</");
+        self.out.push_str(name);
+        self.out.push('>');
+    }
+}
+
+fn encode_xml(value: &ObjectMap, root: &str, attr_prefix: &str, text_key: &str) -> Resolved {
+    if attr_prefix.is_empty() {
+        return Err("attr_prefix must not be empty".into());
+    }
+
+    let mut encoder = Encoder {
+        attr_prefix,
+        text_key,
+        out: String::new(),
+    };
+    encoder.object(root, value)?;
+
+    Ok(encoder.out.into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct EncodeXml;
+
+impl Function for EncodeXml {
+    fn identifier(&self) -> &'static str {
+        "encode_xml"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::OBJECT,
+                required: true,
+            },
+            Parameter {
+                keyword: "root",
+                kind: kind::BYTES,
+                required: false,
+            },
+            Parameter {
+                keyword: "attr_prefix",
+                kind: kind::BYTES,
+                required: false,
+            },
+            Parameter {
+                keyword: "text_key",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "nested objects",
+                source: r#"encode_xml!({"user": {"name": "alice", "role": "admin"}})"#,
+                result: Ok(r#""<root><user><name>alice</name><role>admin</role></user></root>""#),
+            },
+            Example {
+                title: "attributes and text",
+                source: r#"encode_xml!({"@version": 2, "message": {"@level": "warn", "text": "disk < 10%"}}, root: "event")"#,
+                result: Ok(
+                    r#""<event version=\"2\"><message level=\"warn\">disk &lt; 10%</message></event>""#,
+                ),
+            },
+            Example {
+                title: "arrays repeat the element",
+                source: r#"encode_xml!({"tag": ["a", "b"], "empty": null})"#,
+                result: Ok(r#""<root><empty/><tag>a</tag><tag>b</tag></root>""#),
+            },
+            Example {
+                title: "round trip through parse_xml",
+                source: r#"parse_xml!(encode_xml!({"item": [{"@id": "a", "text": "x"}, {"@id": "b", "text": "y"}]})).root"#,
+                result: Ok(r#"{"item": [{"@id": "a", "text": "x"}, {"@id": "b", "text": "y"}]}"#),
+            },
+            Example {
+                title: "invalid element name",
+                source: r#"encode_xml!({"1st": true})"#,
+                result: Err(
+                    r#"function call error for "encode_xml" at (0:26): invalid XML name "1st""#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let root = arguments.optional("root");
+        let attr_prefix = arguments.optional("attr_prefix");
+        let text_key = arguments.optional("text_key");
+
+        Ok(EncodeXmlFn {
+            value,
+            root,
+            attr_prefix,
+            text_key,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct EncodeXmlFn {
+    value: Box<dyn Expression>,
+    root: Option<Box<dyn Expression>>,
+    attr_prefix: Option<Box<dyn Expression>>,
+    text_key: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for EncodeXmlFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_object()?;
+        let root = match &self.root {
+            Some(root) => root.resolve(ctx)?.try_bytes_utf8_lossy()?.into_owned(),
+            None => "root".to_owned(),
+        };
+        let attr_prefix = match &self.attr_prefix {
+            Some(attr_prefix) => attr_prefix
+                .resolve(ctx)?
+                .try_bytes_utf8_lossy()?
+                .into_owned(),
+            None => "@".to_owned(),
+        };
+        let text_key = match &self.text_key {
+            Some(text_key) => text_key.resolve(ctx)?.try_bytes_utf8_lossy()?.into_owned(),
+            None => "text".to_owned(),
+        };
+
+        encode_xml(&value, &root, &attr_prefix, &text_key)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::bytes().fallible()
+    }
+}
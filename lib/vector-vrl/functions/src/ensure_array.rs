@@ -0,0 +1,107 @@
+use vrl::prelude::*;
+
+fn ensure_array(value: Value, parse_json: bool) -> Value {
+    match value {
+        Value::Null => Value::Array(Vec::new()),
+        Value::Array(_) => value,
+        Value::Bytes(ref bytes) if parse_json => match serde_json::from_slice(bytes) {
+            Ok(array @ Value::Array(_)) => array,
+            // Anything but a JSON array is kept as a plain string.
+            _ => Value::Array(vec![value]),
+        },
+        value => Value::Array(vec![value]),
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct EnsureArray;
+
+impl Function for EnsureArray {
+    fn identifier(&self) -> &'static str {
+        "ensure_array"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ANY,
+                required: true,
+            },
+            Parameter {
+                keyword: "parse_json",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "scalar",
+                source: r#"ensure_array("web")"#,
+                result: Ok(r#"["web"]"#),
+            },
+            Example {
+                title: "array",
+                source: r#"ensure_array(["web", "prod"])"#,
+                result: Ok(r#"["web", "prod"]"#),
+            },
+            Example {
+                title: "null",
+                source: r#"ensure_array(null)"#,
+                result: Ok("[]"),
+            },
+            Example {
+                title: "JSON array string",
+                source: r#"ensure_array("[\"web\", 1]", parse_json: true)"#,
+                result: Ok(r#"["web", 1]"#),
+            },
+            Example {
+                title: "JSON array string without parse_json",
+                source: r#"ensure_array("[\"web\", 1]")"#,
+                result: Ok(r#"["[\"web\", 1]"]"#),
+            },
+            Example {
+                title: "non-array string with parse_json",
+                source: r#"ensure_array("{\"web\": 1}", parse_json: true)"#,
+                result: Ok(r#"["{\"web\": 1}"]"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let parse_json = arguments.optional("parse_json");
+
+        Ok(EnsureArrayFn { value, parse_json }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct EnsureArrayFn {
+    value: Box<dyn Expression>,
+    parse_json: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for EnsureArrayFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let parse_json = match &self.parse_json {
+            Some(parse_json) => parse_json.resolve(ctx)?.try_boolean()?,
+            None => false,
+        };
+
+        Ok(ensure_array(value, parse_json))
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::array(Collection::any()).infallible()
+    }
+}
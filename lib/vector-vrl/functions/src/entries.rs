@@ -0,0 +1,377 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug, Default)]
+enum Duplicates {
+    Error,
+    First,
+    #[default]
+    Last,
+}
+
+impl Duplicates {
+    fn all_value() -> Vec<Value> {
+        ["error", "first", "last"]
+            .into_iter()
+            .map(Value::from)
+            .collect()
+    }
+
+    fn from_value(value: &Value) -> Self {
+        match value
+            .try_bytes_utf8_lossy()
+            .expect("duplicates not bytes")
+            .as_ref()
+        {
+            "error" => Self::Error,
+            "first" => Self::First,
+            "last" => Self::Last,
+            _ => unreachable!("enum invariant"),
+        }
+    }
+}
+
+fn entries(value: ObjectMap) -> Value {
+    Value::Array(
+        value
+            .into_iter()
+            .map(|(key, value)| Value::Array(vec![Value::from(key), value]))
+            .collect(),
+    )
+}
+
+/// Inserts a `[key, value]` pair into `object`, resolving duplicate keys according to
+/// `duplicates`. `entry` describes where the pair came from in error messages.
+fn insert_pair(
+    object: &mut ObjectMap,
+    pair: Value,
+    duplicates: Duplicates,
+    entry: &str,
+) -> Result<(), ExpressionError> {
+    let pair = match pair {
+        Value::Array(pair) => <[Value; 2]>::try_from(pair).ok(),
+        _ => None,
+    };
+    let (key, value): (KeyString, _) = match pair {
+        Some([Value::Bytes(key), value]) => (String::from_utf8_lossy(&key).into(), value),
+        _ => return Err(format!("{entry} must be a [key, value] pair with a string key").into()),
+    };
+
+    match duplicates {
+        Duplicates::Error if object.contains_key(&key) => {
+            return Err(format!("duplicate key {:?} at {entry}", key.as_str()).into());
+        }
+        Duplicates::First if object.contains_key(&key) => {}
+        _ => {
+            object.insert(key, value);
+        }
+    }
+
+    Ok(())
+}
+
+fn from_entries(value: Vec<Value>, duplicates: Duplicates) -> Resolved {
+    let mut object = ObjectMap::new();
+
+    for (index, entry) in value.into_iter().enumerate() {
+        insert_pair(&mut object, entry, duplicates, &format!("entry {index}"))?;
+    }
+
+    Ok(Value::Object(object))
+}
+
+fn map_entries<T>(
+    value: ObjectMap,
+    duplicates: Duplicates,
+    ctx: &mut Context,
+    runner: closure::Runner<T>,
+) -> Resolved
+where
+    T: Fn(&mut Context) -> Resolved,
+{
+    let mut object = ObjectMap::new();
+
+    for (key, value) in value {
+        let pair = runner.run_key_value(ctx, &key, &value)?;
+        insert_pair(
+            &mut object,
+            pair,
+            duplicates,
+            &format!("entry for key {:?}", key.as_str()),
+        )?;
+    }
+
+    Ok(Value::Object(object))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Entries;
+
+impl Function for Entries {
+    fn identifier(&self) -> &'static str {
+        "entries"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::OBJECT,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "object to entries",
+                source: r#"entries({"host": "web-1", "status": 200})"#,
+                result: Ok(r#"[["host", "web-1"], ["status", 200]]"#),
+            },
+            Example {
+                title: "round trip",
+                source: r#"from_entries!(entries({"host": "web-1", "tags": ["a", "b"]}))"#,
+                result: Ok(r#"{"host": "web-1", "tags": ["a", "b"]}"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(EntriesFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct EntriesFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for EntriesFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_object()?;
+
+        Ok(entries(value))
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::array(Collection::from_unknown(Kind::array(Collection::any()))).infallible()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct FromEntries;
+
+impl Function for FromEntries {
+    fn identifier(&self) -> &'static str {
+        "from_entries"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "duplicates",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "entries to object",
+                source: r#"from_entries!([["host", "web-1"], ["status", 200]])"#,
+                result: Ok(r#"{"host": "web-1", "status": 200}"#),
+            },
+            Example {
+                title: "last duplicate wins by default",
+                source: r#"from_entries!([["env", "dev"], ["env", "prod"]])"#,
+                result: Ok(r#"{"env": "prod"}"#),
+            },
+            Example {
+                title: "keep the first duplicate",
+                source: r#"from_entries!([["env", "dev"], ["env", "prod"]], duplicates: "first")"#,
+                result: Ok(r#"{"env": "dev"}"#),
+            },
+            Example {
+                title: "reject duplicates",
+                source: r#"from_entries([["env", "dev"], ["env", "prod"]], duplicates: "error") ?? "duplicate""#,
+                result: Ok("duplicate"),
+            },
+            Example {
+                title: "malformed entry",
+                source: r#"from_entries([["env"]]) ?? "malformed""#,
+                result: Ok("malformed"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let duplicates = arguments
+            .optional_enum("duplicates", &Duplicates::all_value(), state)?
+            .map(|duplicates| Duplicates::from_value(&duplicates))
+            .unwrap_or_default();
+
+        Ok(FromEntriesFn { value, duplicates }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FromEntriesFn {
+    value: Box<dyn Expression>,
+    duplicates: Duplicates,
+}
+
+impl FunctionExpression for FromEntriesFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_array()?;
+
+        from_entries(value, self.duplicates)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::object(Collection::any()).fallible()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct MapEntries;
+
+impl Function for MapEntries {
+    fn identifier(&self) -> &'static str {
+        "map_entries"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::OBJECT,
+                required: true,
+            },
+            Parameter {
+                keyword: "duplicates",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "transform keys and values",
+                source: r#"map_entries!({"Host": " web-1 ", "Env": "prod "}) -> |key, value| { [downcase(key), strip_whitespace(value)] }"#,
+                result: Ok(r#"{"env": "prod", "host": "web-1"}"#),
+            },
+            Example {
+                title: "last duplicate wins by default",
+                source: r#"map_entries!({"a": 1, "b": 2}) -> |_key, value| { ["n", value] }"#,
+                result: Ok(r#"{"n": 2}"#),
+            },
+            Example {
+                title: "keep the first duplicate",
+                source: r#"map_entries!({"a": 1, "b": 2}, duplicates: "first") -> |_key, value| { ["n", value] }"#,
+                result: Ok(r#"{"n": 1}"#),
+            },
+            Example {
+                title: "reject duplicates",
+                source: r#"map_entries({"a": 1, "b": 2}, duplicates: "error") -> |_key, value| { ["n", value] } ?? "duplicate""#,
+                result: Ok("duplicate"),
+            },
+            Example {
+                title: "malformed pair",
+                source: r#"map_entries({"a": 1}) -> |key, _value| { [key] } ?? "malformed""#,
+                result: Ok("malformed"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let duplicates = arguments
+            .optional_enum("duplicates", &Duplicates::all_value(), state)?
+            .map(|duplicates| Duplicates::from_value(&duplicates))
+            .unwrap_or_default();
+        let closure = arguments.required_closure()?;
+
+        Ok(MapEntriesFn {
+            value,
+            duplicates,
+            closure,
+        }
+        .as_expr())
+    }
+
+    fn closure(&self) -> Option<closure::Definition> {
+        use closure::{Definition, Input, Output, Variable, VariableKind};
+
+        Some(Definition {
+            inputs: vec![Input {
+                parameter_keyword: "value",
+                kind: Kind::object(Collection::any()),
+                variables: vec![
+                    Variable {
+                        kind: VariableKind::TargetInnerKey,
+                    },
+                    Variable {
+                        kind: VariableKind::TargetInnerValue,
+                    },
+                ],
+                output: Output::Kind(Kind::array(Collection::any())),
+                example: Example {
+                    title: "transform keys and values",
+                    source: r#"map_entries!({"Host": "web-1"}) -> |key, value| { [downcase(key), upcase(value)] }"#,
+                    result: Ok(r#"{"host": "WEB-1"}"#),
+                },
+            }],
+            is_iterator: true,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct MapEntriesFn {
+    value: Box<dyn Expression>,
+    duplicates: Duplicates,
+    closure: FunctionClosure,
+}
+
+impl FunctionExpression for MapEntriesFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_object()?;
+        let FunctionClosure {
+            variables,
+            block,
+            block_type_def: _,
+        } = &self.closure;
+        let runner = closure::Runner::new(variables, |ctx| block.resolve(ctx));
+
+        map_entries(value, self.duplicates, ctx, runner)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::object(Collection::any()).fallible()
+    }
+}
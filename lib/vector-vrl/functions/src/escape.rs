@@ -0,0 +1,296 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+enum EscapeContext {
+    Regex,
+    Sql,
+    Shell,
+    Json,
+}
+
+impl EscapeContext {
+    fn all_value() -> Vec<Value> {
+        ["regex", "sql", "shell", "json"]
+            .into_iter()
+            .map(Value::from)
+            .collect()
+    }
+
+    fn from_value(value: &Value) -> Self {
+        match value
+            .try_bytes_utf8_lossy()
+            .expect("context not bytes")
+            .as_ref()
+        {
+            "regex" => Self::Regex,
+            "sql" => Self::Sql,
+            "shell" => Self::Shell,
+            "json" => Self::Json,
+            _ => unreachable!("enum invariant"),
+        }
+    }
+}
+
+fn escape(value: &str, context: EscapeContext) -> String {
+    match context {
+        EscapeContext::Regex => regex::escape(value),
+        // The contents of a single quoted SQL string literal. Backslashes and NUL are escaped too,
+        // since MySQL treats backslashes as escape characters by default, where `\'` would
+        // otherwise end the literal.
+        EscapeContext::Sql => {
+            let mut escaped = String::with_capacity(value.len());
+            for c in value.chars() {
+                match c {
+                    '\'' => escaped.push_str("''"),
+                    '\\' => escaped.push_str(r"\\"),
+                    '\0' => escaped.push_str(r"\0"),
+                    c => escaped.push(c),
+                }
+            }
+            escaped
+        }
+        // A single quoted POSIX shell word, closing the quotes around any embedded single quote.
+        EscapeContext::Shell => format!("'{}'", value.replace('\'', r"'\''")),
+        // The contents of a JSON string, without the surrounding double quotes.
+        EscapeContext::Json => {
+            let quoted = serde_json::to_string(value).expect("strings always serialize");
+            quoted[1..quoted.len() - 1].to_owned()
+        }
+    }
+}
+
+/// Removes backslash escapes, taking the character following each backslash literally.
+fn unescape_backslashes(value: &str) -> Result<String, ExpressionError> {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            unescaped.push(chars.next().ok_or("trailing backslash")?);
+        } else {
+            unescaped.push(c);
+        }
+    }
+    Ok(unescaped)
+}
+
+/// Decodes the contents of a single quoted SQL string literal: doubled single quotes and MySQL's
+/// backslash escape sequences.
+fn unescape_sql(value: &str) -> Result<String, ExpressionError> {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' if chars.peek() == Some(&'\'') => {
+                chars.next();
+                unescaped.push('\'');
+            }
+            '\\' => unescaped.push(match chars.next().ok_or("trailing backslash")? {
+                '0' => '\0',
+                'b' => '\u{8}',
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                'Z' => '\u{1a}',
+                c => c,
+            }),
+            c => unescaped.push(c),
+        }
+    }
+    Ok(unescaped)
+}
+
+/// Unquotes a shell word made of single quoted, backslash escaped, and unquoted characters.
+fn unescape_shell(value: &str) -> Result<String, ExpressionError> {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => loop {
+                match chars.next() {
+                    Some('\'') => break,
+                    Some(c) => unescaped.push(c),
+                    None => return Err("unterminated single quote".into()),
+                }
+            },
+            '\\' => unescaped.push(chars.next().ok_or("trailing backslash")?),
+            c => unescaped.push(c),
+        }
+    }
+    Ok(unescaped)
+}
+
+fn unescape(value: &str, context: EscapeContext) -> Resolved {
+    let unescaped = match context {
+        EscapeContext::Regex => unescape_backslashes(value)?,
+        EscapeContext::Sql => unescape_sql(value)?,
+        EscapeContext::Shell => unescape_shell(value)?,
+        EscapeContext::Json => serde_json::from_str::<String>(&format!("\"{value}\""))
+            .map_err(|err| format!("invalid JSON string: {err}"))?,
+    };
+
+    Ok(unescaped.into())
+}
+
+const PARAMETERS: &[Parameter] = &[
+    Parameter {
+        keyword: "value",
+        kind: kind::BYTES,
+        required: true,
+    },
+    Parameter {
+        keyword: "context",
+        kind: kind::BYTES,
+        required: true,
+    },
+];
+
+fn compile(arguments: ArgumentList, state: &TypeState, unescape: bool) -> Compiled {
+    let value = arguments.required("value");
+    let context = EscapeContext::from_value(&arguments.required_enum(
+        "context",
+        &EscapeContext::all_value(),
+        state,
+    )?);
+
+    Ok(EscapeFn {
+        value,
+        context,
+        unescape,
+    }
+    .as_expr())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Escape;
+
+impl Function for Escape {
+    fn identifier(&self) -> &'static str {
+        "escape"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        PARAMETERS
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "regex",
+                source: r#"escape("1.5+[x]", "regex")"#,
+                result: Ok(r#""1\\.5\\+\\[x\\]""#),
+            },
+            Example {
+                title: "sql",
+                source: r#"escape("O'Brien'; DROP TABLE users; --", "sql")"#,
+                result: Ok(r#""O''Brien''; DROP TABLE users; --""#),
+            },
+            Example {
+                title: "sql with a backslash",
+                source: r#"escape("\\'; DROP TABLE users; --", "sql")"#,
+                result: Ok(r#""\\\\''; DROP TABLE users; --""#),
+            },
+            Example {
+                title: "shell",
+                source: r#"escape("it's $(rm -rf /)", "shell")"#,
+                result: Ok(r#""'it'\\''s $(rm -rf /)'""#),
+            },
+            Example {
+                title: "json",
+                source: r#"escape("say \"hi\"\n", "json")"#,
+                result: Ok(r#""say \\\"hi\\\"\\n""#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        compile(arguments, state, false)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Unescape;
+
+impl Function for Unescape {
+    fn identifier(&self) -> &'static str {
+        "unescape"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        PARAMETERS
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "regex",
+                source: r#"unescape!("1\\.5\\+\\[x\\]", "regex")"#,
+                result: Ok("1.5+[x]"),
+            },
+            Example {
+                title: "sql",
+                source: r#"unescape!("O''Brien", "sql")"#,
+                result: Ok("O'Brien"),
+            },
+            Example {
+                title: "sql with backslash escapes",
+                source: r#"unescape!("C:\\\\logs\\'s", "sql")"#,
+                result: Ok(r#""C:\\logs's""#),
+            },
+            Example {
+                title: "shell",
+                source: r#"unescape!("'it'\\''s $(rm -rf /)'", "shell")"#,
+                result: Ok("it's $(rm -rf /)"),
+            },
+            Example {
+                title: "json",
+                source: r#"unescape!("say \\\"hi\\\"\\n", "json")"#,
+                result: Ok(r#""say \"hi\"\n""#),
+            },
+            Example {
+                title: "unterminated shell quote",
+                source: r#"unescape!("'it", "shell")"#,
+                result: Err(
+                    r#"function call error for "unescape" at (0:25): unterminated single quote"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        compile(arguments, state, true)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct EscapeFn {
+    value: Box<dyn Expression>,
+    context: EscapeContext,
+    unescape: bool,
+}
+
+impl FunctionExpression for EscapeFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let value = value.try_bytes_utf8_lossy()?;
+
+        if self.unescape {
+            unescape(&value, self.context)
+        } else {
+            Ok(escape(&value, self.context).into())
+        }
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::bytes().maybe_fallible(self.unescape)
+    }
+}
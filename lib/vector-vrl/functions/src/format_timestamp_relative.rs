@@ -0,0 +1,121 @@
+use chrono::{DateTime, Utc};
+use vrl::prelude::*;
+
+const UNITS: &[(i64, &str)] = &[
+    (365 * 24 * 60 * 60, "year"),
+    (30 * 24 * 60 * 60, "month"),
+    (24 * 60 * 60, "day"),
+    (60 * 60, "hour"),
+    (60, "minute"),
+    (1, "second"),
+];
+
+/// Offsets smaller than this many seconds, in either direction, are rendered as "just now".
+const JUST_NOW_SECONDS: i64 = 10;
+
+fn format_timestamp_relative(value: DateTime<Utc>, base: DateTime<Utc>) -> Resolved {
+    let offset = value.signed_duration_since(base).num_seconds();
+    let seconds = offset.abs();
+
+    if seconds < JUST_NOW_SECONDS {
+        return Ok("just now".into());
+    }
+
+    let (size, unit) = UNITS
+        .iter()
+        .find(|(size, _)| seconds >= *size)
+        .expect("seconds is at least one");
+    let count = seconds / size;
+    let plural = if count == 1 { "" } else { "s" };
+
+    let humanized = if offset < 0 {
+        format!("{count} {unit}{plural} ago")
+    } else {
+        format!("in {count} {unit}{plural}")
+    };
+
+    Ok(humanized.into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct FormatTimestampRelative;
+
+impl Function for FormatTimestampRelative {
+    fn identifier(&self) -> &'static str {
+        "format_timestamp_relative"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::TIMESTAMP,
+                required: true,
+            },
+            Parameter {
+                keyword: "base",
+                kind: kind::TIMESTAMP,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "minutes in the past",
+                source: r#"format_timestamp_relative(t'2021-02-10T23:27:00Z', base: t'2021-02-10T23:32:00Z')"#,
+                result: Ok("5 minutes ago"),
+            },
+            Example {
+                title: "hours in the future",
+                source: r#"format_timestamp_relative(t'2021-02-11T01:32:00Z', base: t'2021-02-10T23:32:00Z')"#,
+                result: Ok("in 2 hours"),
+            },
+            Example {
+                title: "singular unit",
+                source: r#"format_timestamp_relative(t'2021-02-09T23:32:00Z', base: t'2021-02-10T23:32:00Z')"#,
+                result: Ok("1 day ago"),
+            },
+            Example {
+                title: "just now",
+                source: r#"format_timestamp_relative(t'2021-02-10T23:31:55Z', base: t'2021-02-10T23:32:00Z')"#,
+                result: Ok("just now"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let base = arguments.optional("base");
+
+        Ok(FormatTimestampRelativeFn { value, base }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FormatTimestampRelativeFn {
+    value: Box<dyn Expression>,
+    base: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for FormatTimestampRelativeFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_timestamp()?;
+        let base = match &self.base {
+            Some(base) => base.resolve(ctx)?.try_timestamp()?,
+            None => Utc::now(),
+        };
+
+        format_timestamp_relative(value, base)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::bytes().infallible()
+    }
+}
@@ -0,0 +1,153 @@
+use chrono::DateTime;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+enum Unit {
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl Unit {
+    fn all_value() -> Vec<Value> {
+        ["seconds", "milliseconds", "microseconds", "nanoseconds"]
+            .into_iter()
+            .map(Value::from)
+            .collect()
+    }
+
+    fn from_value(value: &Value) -> Self {
+        match value
+            .try_bytes_utf8_lossy()
+            .expect("unit not bytes")
+            .as_ref()
+        {
+            "seconds" => Self::Seconds,
+            "milliseconds" => Self::Milliseconds,
+            "microseconds" => Self::Microseconds,
+            "nanoseconds" => Self::Nanoseconds,
+            _ => unreachable!("enum invariant"),
+        }
+    }
+
+    /// Guesses the unit of an epoch value from its magnitude.
+    ///
+    /// A value is interpreted in the coarsest unit that keeps it before the year 5000. For example,
+    /// 10^11 seconds is in the year 5138, so values of that magnitude are taken to be milliseconds.
+    fn detect(value: i64) -> Self {
+        match value.unsigned_abs() {
+            0..=99_999_999_999 => Self::Seconds,
+            100_000_000_000..=99_999_999_999_999 => Self::Milliseconds,
+            100_000_000_000_000..=99_999_999_999_999_999 => Self::Microseconds,
+            _ => Self::Nanoseconds,
+        }
+    }
+
+    fn nanoseconds(self) -> i64 {
+        match self {
+            Self::Seconds => 1_000_000_000,
+            Self::Milliseconds => 1_000_000,
+            Self::Microseconds => 1_000,
+            Self::Nanoseconds => 1,
+        }
+    }
+}
+
+fn from_epoch_auto(value: i64, unit: Option<Unit>) -> Resolved {
+    let unit = unit.unwrap_or_else(|| Unit::detect(value));
+    let per_second = 1_000_000_000 / unit.nanoseconds();
+
+    let seconds = value.div_euclid(per_second);
+    let nanoseconds = value.rem_euclid(per_second) * unit.nanoseconds();
+
+    DateTime::from_timestamp(seconds, nanoseconds as u32)
+        .map(Value::from)
+        .ok_or_else(|| format!("epoch value {value} is out of range for a timestamp").into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct FromEpochAuto;
+
+impl Function for FromEpochAuto {
+    fn identifier(&self) -> &'static str {
+        "from_epoch_auto"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::INTEGER,
+                required: true,
+            },
+            Parameter {
+                keyword: "unit",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "seconds",
+                source: r#"from_epoch_auto!(1609459200)"#,
+                result: Ok("t'2021-01-01T00:00:00Z'"),
+            },
+            Example {
+                title: "milliseconds",
+                source: r#"from_epoch_auto!(1609459200123)"#,
+                result: Ok("t'2021-01-01T00:00:00.123Z'"),
+            },
+            Example {
+                title: "microseconds",
+                source: r#"from_epoch_auto!(1609459200123456)"#,
+                result: Ok("t'2021-01-01T00:00:00.123456Z'"),
+            },
+            Example {
+                title: "nanoseconds",
+                source: r#"from_epoch_auto!(1609459200123456789)"#,
+                result: Ok("t'2021-01-01T00:00:00.123456789Z'"),
+            },
+            Example {
+                title: "explicit unit",
+                source: r#"from_epoch_auto!(1609459200, unit: "milliseconds")"#,
+                result: Ok("t'1970-01-19T15:04:19.200Z'"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let unit = arguments
+            .optional_enum("unit", &Unit::all_value(), state)?
+            .map(|unit| Unit::from_value(&unit));
+
+        Ok(FromEpochAutoFn { value, unit }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FromEpochAutoFn {
+    value: Box<dyn Expression>,
+    unit: Option<Unit>,
+}
+
+impl FunctionExpression for FromEpochAutoFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_integer()?;
+
+        from_epoch_auto(value, self.unit)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::timestamp().fallible()
+    }
+}
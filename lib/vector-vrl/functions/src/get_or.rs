@@ -0,0 +1,136 @@
+use vrl::path::{OwnedSegment, OwnedValuePath};
+use vrl::prelude::*;
+
+fn get_or(value: &Value, path: Vec<Value>, default: Value, null_as_missing: bool) -> Resolved {
+    let mut value_path = OwnedValuePath::root();
+    for segment in path {
+        let segment = match segment {
+            Value::Bytes(field) => OwnedSegment::field(String::from_utf8_lossy(&field).as_ref()),
+            Value::Integer(index) => OwnedSegment::index(index as isize),
+            value => {
+                return Err(format!(
+                    "path segment must be either string or integer, not {}",
+                    value.kind()
+                )
+                .into())
+            }
+        };
+        value_path.push(segment);
+    }
+
+    Ok(match value.get(&value_path) {
+        Some(Value::Null) if null_as_missing => default,
+        Some(found) => found.clone(),
+        None => default,
+    })
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct GetOr;
+
+impl Function for GetOr {
+    fn identifier(&self) -> &'static str {
+        "get_or"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::OBJECT | kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "path",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "default",
+                kind: kind::ANY,
+                required: true,
+            },
+            Parameter {
+                keyword: "null_as_missing",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "existing field",
+                source: r#"get_or!({"http": {"status": 404}}, ["http", "status"], 200)"#,
+                result: Ok("404"),
+            },
+            Example {
+                title: "absent field",
+                source: r#"get_or!({"http": {}}, ["http", "status"], 200)"#,
+                result: Ok("200"),
+            },
+            Example {
+                title: "present null field",
+                source: r#"get_or!({"http": {"status": null}}, ["http", "status"], 200)"#,
+                result: Ok("null"),
+            },
+            Example {
+                title: "present null field treated as missing",
+                source: r#"get_or!({"http": {"status": null}}, ["http", "status"], 200, null_as_missing: true)"#,
+                result: Ok("200"),
+            },
+            Example {
+                title: "array index",
+                source: r#"get_or!({"tags": ["a", "b"]}, ["tags", 5], "none")"#,
+                result: Ok("none"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let path = arguments.required("path");
+        let default = arguments.required("default");
+        let null_as_missing = arguments.optional("null_as_missing");
+
+        Ok(GetOrFn {
+            value,
+            path,
+            default,
+            null_as_missing,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct GetOrFn {
+    value: Box<dyn Expression>,
+    path: Box<dyn Expression>,
+    default: Box<dyn Expression>,
+    null_as_missing: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for GetOrFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let path = self.path.resolve(ctx)?.try_array()?;
+        let default = self.default.resolve(ctx)?;
+        let null_as_missing = match &self.null_as_missing {
+            Some(null_as_missing) => null_as_missing.resolve(ctx)?.try_boolean()?,
+            None => false,
+        };
+
+        get_or(&value, path, default, null_as_missing)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::any().fallible()
+    }
+}
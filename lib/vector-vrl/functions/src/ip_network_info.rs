@@ -0,0 +1,182 @@
+use std::collections::BTreeMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use vrl::prelude::*;
+
+fn ipv4_info(address: Ipv4Addr, prefix: u32) -> ObjectMap {
+    let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+    let network = u32::from(address) & mask;
+    let broadcast = network | !mask;
+
+    // Point-to-point (/31, RFC 3021) and single-address (/32) networks have no reserved network
+    // or broadcast address, so every address is usable by a host.
+    let (first_host, last_host) = if prefix >= 31 {
+        (network, broadcast)
+    } else {
+        (network + 1, broadcast - 1)
+    };
+
+    ObjectMap::from([
+        ("network".into(), Ipv4Addr::from(network).to_string().into()),
+        (
+            "broadcast".into(),
+            Ipv4Addr::from(broadcast).to_string().into(),
+        ),
+        (
+            "first_host".into(),
+            Ipv4Addr::from(first_host).to_string().into(),
+        ),
+        (
+            "last_host".into(),
+            Ipv4Addr::from(last_host).to_string().into(),
+        ),
+        (
+            "num_addresses".into(),
+            Value::Integer(1_i64 << (32 - prefix)),
+        ),
+        ("prefix".into(), Value::Integer(prefix.into())),
+    ])
+}
+
+fn ipv6_info(address: Ipv6Addr, prefix: u32) -> ObjectMap {
+    let mask = u128::MAX.checked_shl(128 - prefix).unwrap_or(0);
+    let network = u128::from(address) & mask;
+    let last = network | !mask;
+
+    // IPv6 has no broadcast address, and the address count only fits an integer for prefixes
+    // of /66 and longer.
+    let num_addresses = 1_u128
+        .checked_shl(128 - prefix)
+        .and_then(|count| i64::try_from(count).ok())
+        .map_or(Value::Null, Value::Integer);
+
+    ObjectMap::from([
+        ("network".into(), Ipv6Addr::from(network).to_string().into()),
+        ("broadcast".into(), Value::Null),
+        (
+            "first_host".into(),
+            Ipv6Addr::from(network).to_string().into(),
+        ),
+        ("last_host".into(), Ipv6Addr::from(last).to_string().into()),
+        ("num_addresses".into(), num_addresses),
+        ("prefix".into(), Value::Integer(prefix.into())),
+    ])
+}
+
+fn ip_network_info(value: &str) -> Resolved {
+    let invalid = || format!("unable to parse CIDR: {value:?}");
+
+    let (address, prefix) = value.split_once('/').ok_or_else(invalid)?;
+    let address = address.parse::<IpAddr>().map_err(|_| invalid())?;
+    let prefix = prefix.parse::<u32>().map_err(|_| invalid())?;
+
+    let info = match address {
+        IpAddr::V4(address) if prefix <= 32 => ipv4_info(address, prefix),
+        IpAddr::V6(address) if prefix <= 128 => ipv6_info(address, prefix),
+        _ => return Err(invalid().into()),
+    };
+
+    Ok(Value::Object(info))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct IpNetworkInfo;
+
+impl Function for IpNetworkInfo {
+    fn identifier(&self) -> &'static str {
+        "ip_network_info"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "cidr",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "IPv4 /24",
+                source: r#"ip_network_info!("192.168.10.32/24")"#,
+                result: Ok(indoc! {r#"{
+                    "network": "192.168.10.0",
+                    "broadcast": "192.168.10.255",
+                    "first_host": "192.168.10.1",
+                    "last_host": "192.168.10.254",
+                    "num_addresses": 256,
+                    "prefix": 24
+                }"#}),
+            },
+            Example {
+                title: "IPv4 /30",
+                source: r#"ip_network_info!("10.0.0.6/30")"#,
+                result: Ok(indoc! {r#"{
+                    "network": "10.0.0.4",
+                    "broadcast": "10.0.0.7",
+                    "first_host": "10.0.0.5",
+                    "last_host": "10.0.0.6",
+                    "num_addresses": 4,
+                    "prefix": 30
+                }"#}),
+            },
+            Example {
+                title: "IPv6 /120",
+                source: r#"ip_network_info!("2001:db8::1/120")"#,
+                result: Ok(indoc! {r#"{
+                    "network": "2001:db8::",
+                    "broadcast": null,
+                    "first_host": "2001:db8::",
+                    "last_host": "2001:db8::ff",
+                    "num_addresses": 256,
+                    "prefix": 120
+                }"#}),
+            },
+            Example {
+                title: "invalid prefix",
+                source: r#"ip_network_info("10.0.0.0/33") ?? "invalid""#,
+                result: Ok("invalid"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let cidr = arguments.required("cidr");
+
+        Ok(IpNetworkInfoFn { cidr }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct IpNetworkInfoFn {
+    cidr: Box<dyn Expression>,
+}
+
+impl FunctionExpression for IpNetworkInfoFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let cidr = self.cidr.resolve(ctx)?;
+
+        ip_network_info(&cidr.try_bytes_utf8_lossy()?)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::object(inner_kind()).fallible()
+    }
+}
+
+fn inner_kind() -> BTreeMap<Field, Kind> {
+    BTreeMap::from([
+        ("network".into(), Kind::bytes()),
+        ("broadcast".into(), Kind::bytes().or_null()),
+        ("first_host".into(), Kind::bytes()),
+        ("last_host".into(), Kind::bytes()),
+        ("num_addresses".into(), Kind::integer().or_null()),
+        ("prefix".into(), Kind::integer()),
+    ])
+}
@@ -0,0 +1,126 @@
+use vrl::prelude::*;
+
+fn is_numeric_string(value: &str, allow_float: bool) -> bool {
+    let value = value.trim();
+
+    if value.parse::<i64>().is_ok() {
+        return true;
+    }
+
+    // `f64::from_str` also accepts `inf`, `infinity` and `nan`, which are not numbers as far as
+    // the caller is concerned.
+    allow_float
+        && !value
+            .chars()
+            .any(|c| c.is_alphabetic() && !matches!(c, 'e' | 'E'))
+        && value.parse::<f64>().is_ok()
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct IsNumericString;
+
+impl Function for IsNumericString {
+    fn identifier(&self) -> &'static str {
+        "is_numeric_string"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "allow_float",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "integer",
+                source: r#"is_numeric_string("-42")"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "surrounding whitespace",
+                source: r#"is_numeric_string(" 8080 ")"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "float",
+                source: r#"is_numeric_string("3.14")"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "scientific notation",
+                source: r#"is_numeric_string("1.5e3")"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "float without allow_float",
+                source: r#"is_numeric_string("3.14", allow_float: false)"#,
+                result: Ok("false"),
+            },
+            Example {
+                title: "integer without allow_float",
+                source: r#"is_numeric_string("100", allow_float: false)"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "infinity",
+                source: r#"is_numeric_string("inf")"#,
+                result: Ok("false"),
+            },
+            Example {
+                title: "garbage",
+                source: r#"is_numeric_string("12abc")"#,
+                result: Ok("false"),
+            },
+            Example {
+                title: "empty string",
+                source: r#"is_numeric_string("")"#,
+                result: Ok("false"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let allow_float = arguments.optional("allow_float");
+
+        Ok(IsNumericStringFn { value, allow_float }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct IsNumericStringFn {
+    value: Box<dyn Expression>,
+    allow_float: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for IsNumericStringFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let value = value.try_bytes_utf8_lossy()?;
+        let allow_float = match &self.allow_float {
+            Some(allow_float) => allow_float.resolve(ctx)?.try_boolean()?,
+            None => true,
+        };
+
+        Ok(is_numeric_string(&value, allow_float).into())
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::boolean().infallible()
+    }
+}
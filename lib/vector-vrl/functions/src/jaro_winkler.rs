@@ -0,0 +1,185 @@
+use vrl::prelude::*;
+
+/// The scaling factor from Winkler's original paper.
+const DEFAULT_PREFIX_SCALE: f64 = 0.1;
+
+/// The longest common prefix that is rewarded.
+const MAX_PREFIX: usize = 4;
+
+fn jaro(a: &[char], b: &[char]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matched = vec![false; a.len()];
+    let mut b_matched = vec![false; b.len()];
+    let mut matches = 0;
+
+    for (i, c) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        for j in start..end {
+            if !b_matched[j] && b[j] == *c {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let a_matches = a.iter().zip(&a_matched).filter(|(_, m)| **m);
+    let b_matches = b.iter().zip(&b_matched).filter(|(_, m)| **m);
+    let transpositions = a_matches
+        .zip(b_matches)
+        .filter(|((a, _), (b, _))| a != b)
+        .count()
+        / 2;
+
+    let matches = matches as f64;
+    (matches / a.len() as f64
+        + matches / b.len() as f64
+        + (matches - transpositions as f64) / matches)
+        / 3.0
+}
+
+fn jaro_winkler(a: &str, b: &str, prefix_scale: f64) -> Resolved {
+    if !(0.0..=0.25).contains(&prefix_scale) {
+        return Err(format!("prefix_scale must be between 0 and 0.25, got {prefix_scale}").into());
+    }
+
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    let similarity = jaro(&a, &b);
+    let prefix = a
+        .iter()
+        .zip(&b)
+        .take(MAX_PREFIX)
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    Ok(Value::from_f64_or_zero(
+        similarity + prefix as f64 * prefix_scale * (1.0 - similarity),
+    ))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct JaroWinkler;
+
+impl Function for JaroWinkler {
+    fn identifier(&self) -> &'static str {
+        "jaro_winkler"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "a",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "b",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "prefix_scale",
+                kind: kind::FLOAT,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "transposition",
+                source: r#"round(jaro_winkler("MARTHA", "MARHTA"), 4)"#,
+                result: Ok("0.9611"),
+            },
+            Example {
+                title: "different lengths",
+                source: r#"round(jaro_winkler("DWAYNE", "DUANE"), 4)"#,
+                result: Ok("0.84"),
+            },
+            Example {
+                title: "no common prefix",
+                source: r#"round(jaro_winkler("DIXON", "DICKSONX"), 4)"#,
+                result: Ok("0.8133"),
+            },
+            Example {
+                title: "identical",
+                source: r#"jaro_winkler("kafka", "kafka")"#,
+                result: Ok("1.0"),
+            },
+            Example {
+                title: "nothing in common",
+                source: r#"jaro_winkler("abc", "xyz")"#,
+                result: Ok("0.0"),
+            },
+            Example {
+                title: "unicode",
+                source: r#"round(jaro_winkler("café", "cafe"), 4)"#,
+                result: Ok("0.8833"),
+            },
+            Example {
+                title: "without prefix scaling",
+                source: r#"round(jaro_winkler!("MARTHA", "MARHTA", prefix_scale: 0.0), 4)"#,
+                result: Ok("0.9444"),
+            },
+            Example {
+                title: "invalid prefix scale",
+                source: r#"jaro_winkler("a", "b", prefix_scale: 0.5) ?? -1.0"#,
+                result: Ok("-1.0"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let a = arguments.required("a");
+        let b = arguments.required("b");
+        let prefix_scale = arguments.optional("prefix_scale");
+
+        Ok(JaroWinklerFn { a, b, prefix_scale }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct JaroWinklerFn {
+    a: Box<dyn Expression>,
+    b: Box<dyn Expression>,
+    prefix_scale: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for JaroWinklerFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let a = self.a.resolve(ctx)?;
+        let a = a.try_bytes_utf8_lossy()?;
+        let b = self.b.resolve(ctx)?;
+        let b = b.try_bytes_utf8_lossy()?;
+        let prefix_scale = match &self.prefix_scale {
+            Some(prefix_scale) => prefix_scale.resolve(ctx)?.try_float()?,
+            None => DEFAULT_PREFIX_SCALE,
+        };
+
+        jaro_winkler(&a, &b, prefix_scale)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::float().maybe_fallible(self.prefix_scale.is_some())
+    }
+}
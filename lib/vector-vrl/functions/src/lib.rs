@@ -3,10 +3,51 @@
 use vrl::compiler::Function;
 use vrl::path::OwnedTargetPath;
 
+pub mod accessors;
+pub mod byte_size;
+pub mod coalesce_nullish;
+pub mod concat;
+pub mod crc32;
+pub mod dedupe_consecutive;
+pub mod default_values;
+#[cfg(feature = "encode_csv")]
+pub mod encode_csv;
+#[cfg(feature = "encode_xml")]
+pub mod encode_xml;
+pub mod ensure_array;
+pub mod entries;
+pub mod escape;
+pub mod format_timestamp_relative;
+pub mod from_epoch_auto;
+pub mod get_or;
 pub mod get_secret;
+pub mod ip_network_info;
+pub mod is_numeric_string;
+pub mod jaro_winkler;
+pub mod moving_average;
+pub mod pad;
+pub mod parse_cef_syslog;
+pub mod parse_duration_parts;
+#[cfg(feature = "parse_ini")]
+pub mod parse_ini;
+pub mod parse_json5;
+pub mod partition;
+pub mod pick_omit;
 pub mod remove_secret;
+pub mod rename_keys;
+pub mod repeat;
+pub mod reverse_rotate;
+pub mod sample_n;
+pub mod set_operations;
 pub mod set_secret;
 pub mod set_semantic_meaning;
+pub mod syslog_priority;
+#[cfg(feature = "to_case")]
+pub mod to_case;
+pub mod top_k;
+pub mod validate_json_schema;
+pub mod wrap;
+pub mod zip_object;
 
 #[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug)]
@@ -18,10 +59,67 @@ pub enum MetadataKey {
 pub const LEGACY_METADATA_KEYS: [&str; 2] = ["datadog_api_key", "splunk_hec_token"];
 
 pub fn all() -> Vec<Box<dyn Function>> {
-    vec![
+    #[allow(unused_mut)]
+    let mut functions: Vec<Box<dyn Function>> = vec![
         Box::new(set_semantic_meaning::SetSemanticMeaning) as _,
         Box::new(get_secret::GetSecret) as _,
         Box::new(remove_secret::RemoveSecret) as _,
         Box::new(set_secret::SetSecret) as _,
-    ]
+        Box::new(rename_keys::RenameKeys) as _,
+        Box::new(byte_size::ByteSize) as _,
+        Box::new(top_k::TopK) as _,
+        Box::new(format_timestamp_relative::FormatTimestampRelative) as _,
+        Box::new(coalesce_nullish::CoalesceNullish) as _,
+        Box::new(pad::PadStart) as _,
+        Box::new(pad::PadEnd) as _,
+        Box::new(repeat::Repeat) as _,
+        Box::new(parse_duration_parts::ParseDurationParts) as _,
+        Box::new(ip_network_info::IpNetworkInfo) as _,
+        Box::new(default_values::DefaultValues) as _,
+        Box::new(from_epoch_auto::FromEpochAuto) as _,
+        Box::new(validate_json_schema::ValidateJsonSchema) as _,
+        Box::new(parse_cef_syslog::ParseCefSyslog) as _,
+        Box::new(set_operations::SetIntersection) as _,
+        Box::new(set_operations::SetUnion) as _,
+        Box::new(set_operations::SetDifference) as _,
+        Box::new(entries::Entries) as _,
+        Box::new(entries::FromEntries) as _,
+        Box::new(entries::MapEntries) as _,
+        Box::new(moving_average::MovingAverage) as _,
+        Box::new(accessors::First) as _,
+        Box::new(accessors::Last) as _,
+        Box::new(accessors::Nth) as _,
+        Box::new(get_or::GetOr) as _,
+        Box::new(crc32::Crc32) as _,
+        Box::new(crc32::Crc32c) as _,
+        Box::new(sample_n::SampleN) as _,
+        Box::new(wrap::Wrap) as _,
+        Box::new(zip_object::ZipObject) as _,
+        Box::new(syslog_priority::SyslogPriority) as _,
+        Box::new(is_numeric_string::IsNumericString) as _,
+        Box::new(partition::Partition) as _,
+        Box::new(jaro_winkler::JaroWinkler) as _,
+        Box::new(concat::Concat) as _,
+        Box::new(pick_omit::Pick) as _,
+        Box::new(pick_omit::Omit) as _,
+        Box::new(ensure_array::EnsureArray) as _,
+        Box::new(escape::Escape) as _,
+        Box::new(escape::Unescape) as _,
+        Box::new(dedupe_consecutive::DedupeConsecutive) as _,
+        Box::new(dedupe_consecutive::DedupeConsecutiveBy) as _,
+        Box::new(parse_json5::ParseJson5) as _,
+        Box::new(reverse_rotate::Reverse) as _,
+        Box::new(reverse_rotate::Rotate) as _,
+    ];
+
+    #[cfg(feature = "parse_ini")]
+    functions.push(Box::new(parse_ini::ParseIni) as _);
+    #[cfg(feature = "encode_csv")]
+    functions.push(Box::new(encode_csv::EncodeCsv) as _);
+    #[cfg(feature = "encode_xml")]
+    functions.push(Box::new(encode_xml::EncodeXml) as _);
+    #[cfg(feature = "to_case")]
+    functions.push(Box::new(to_case::ToCase) as _);
+
+    functions
 }
@@ -0,0 +1,139 @@
+use vrl::prelude::*;
+
+fn moving_average(value: Vec<Value>, window: i64, partial: bool) -> Resolved {
+    let window = usize::try_from(window)
+        .ok()
+        .filter(|window| *window > 0)
+        .ok_or_else(|| format!("window must be a positive integer, got {window}"))?;
+
+    let numbers = value
+        .into_iter()
+        .enumerate()
+        .map(|(index, item)| match item {
+            Value::Integer(number) => Ok(number as f64),
+            Value::Float(number) => Ok(number.into_inner()),
+            item => Err(format!(
+                "element {index} must be an integer or float, got {}",
+                item.kind()
+            )),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Each window is summed on its own rather than kept as a running sum, so a large value
+    // leaving the window can't cancel out the small ones that remain.
+    let averages = (0..numbers.len())
+        .map(|index| {
+            let start = (index + 1).saturating_sub(window);
+            let count = index + 1 - start;
+            if count == window || partial {
+                let sum: f64 = numbers[start..=index].iter().sum();
+                Value::from_f64_or_zero(sum / count as f64)
+            } else {
+                Value::Null
+            }
+        })
+        .collect();
+
+    Ok(Value::Array(averages))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct MovingAverage;
+
+impl Function for MovingAverage {
+    fn identifier(&self) -> &'static str {
+        "moving_average"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "window",
+                kind: kind::INTEGER,
+                required: true,
+            },
+            Parameter {
+                keyword: "partial",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "window smaller than the array",
+                source: r#"moving_average!([2, 4, 6, 8, 10], 3)"#,
+                result: Ok("[null, null, 4.0, 6.0, 8.0]"),
+            },
+            Example {
+                title: "average partial windows",
+                source: r#"moving_average!([2, 4, 6, 8, 10], 3, partial: true)"#,
+                result: Ok("[2.0, 3.0, 4.0, 6.0, 8.0]"),
+            },
+            Example {
+                title: "window larger than the array",
+                source: r#"moving_average!([1, 2.5], 5)"#,
+                result: Ok("[null, null]"),
+            },
+            Example {
+                title: "large spike",
+                source: r#"moving_average!([100000000000000000000.0, 1, 1], 1)"#,
+                result: Ok("[100000000000000000000.0, 1.0, 1.0]"),
+            },
+            Example {
+                title: "non-numeric element",
+                source: r#"moving_average([1, "2"], 2) ?? "invalid""#,
+                result: Ok("invalid"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let window = arguments.required("window");
+        let partial = arguments.optional("partial");
+
+        Ok(MovingAverageFn {
+            value,
+            window,
+            partial,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct MovingAverageFn {
+    value: Box<dyn Expression>,
+    window: Box<dyn Expression>,
+    partial: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for MovingAverageFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_array()?;
+        let window = self.window.resolve(ctx)?.try_integer()?;
+        let partial = match &self.partial {
+            Some(partial) => partial.resolve(ctx)?.try_boolean()?,
+            None => false,
+        };
+
+        moving_average(value, window, partial)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::array(Collection::from_unknown(Kind::float().or_null())).fallible()
+    }
+}
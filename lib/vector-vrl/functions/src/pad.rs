@@ -0,0 +1,203 @@
+use unicode_segmentation::UnicodeSegmentation;
+use vrl::prelude::*;
+
+/// Upper bound on the requested width, in graphemes.
+const MAX_WIDTH: i64 = 1024 * 1024;
+
+/// Upper bound on the size of the padded string, to prevent giant allocations from untrusted
+/// input. A single `fill` grapheme can be arbitrarily large, so the width alone doesn't bound it.
+const MAX_BYTES: usize = 1024 * 1024;
+
+#[derive(Clone, Copy, Debug)]
+enum Side {
+    Start,
+    End,
+}
+
+fn pad(value: &str, width: i64, fill: &str, side: Side) -> Resolved {
+    if !(0..=MAX_WIDTH).contains(&width) {
+        return Err(format!("width must be between 0 and {MAX_WIDTH}, got {width}").into());
+    }
+
+    let graphemes = fill.graphemes(true).collect::<Vec<_>>();
+    if graphemes.is_empty() {
+        return Err("fill must not be empty".into());
+    }
+
+    let missing = (width as usize).saturating_sub(value.graphemes(true).count());
+    let partial = graphemes[..missing % graphemes.len()].concat().len();
+    let size = (missing / graphemes.len())
+        .checked_mul(fill.len())
+        .and_then(|size| size.checked_add(partial + value.len()));
+    match size {
+        Some(size) if size <= MAX_BYTES => {}
+        _ => {
+            return Err(format!("padded string would exceed the limit of {MAX_BYTES} bytes").into())
+        }
+    }
+
+    let padding = graphemes
+        .iter()
+        .cycle()
+        .take(missing)
+        .copied()
+        .collect::<String>();
+
+    Ok(match side {
+        Side::Start => format!("{padding}{value}"),
+        Side::End => format!("{value}{padding}"),
+    }
+    .into())
+}
+
+const PARAMETERS: &[Parameter] = &[
+    Parameter {
+        keyword: "value",
+        kind: kind::BYTES,
+        required: true,
+    },
+    Parameter {
+        keyword: "width",
+        kind: kind::INTEGER,
+        required: true,
+    },
+    Parameter {
+        keyword: "fill",
+        kind: kind::BYTES,
+        required: false,
+    },
+];
+
+fn compile(arguments: ArgumentList, side: Side) -> Compiled {
+    let value = arguments.required("value");
+    let width = arguments.required("width");
+    let fill = arguments.optional("fill");
+
+    Ok(PadFn {
+        value,
+        width,
+        fill,
+        side,
+    }
+    .as_expr())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct PadStart;
+
+impl Function for PadStart {
+    fn identifier(&self) -> &'static str {
+        "pad_start"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        PARAMETERS
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "pad with spaces",
+                source: r#""|" + pad_start!("42", 5)"#,
+                result: Ok("|   42"),
+            },
+            Example {
+                title: "pad with a custom fill",
+                source: r#"pad_start!("42", 5, fill: "*")"#,
+                result: Ok("***42"),
+            },
+            Example {
+                title: "width counts graphemes",
+                source: r#"pad_start!("👍🏽", 3, fill: "é")"#,
+                result: Ok("éé👍🏽"),
+            },
+            Example {
+                title: "a multi-kilobyte fill beyond the size limit",
+                source: r#"pad_start("", 1000, fill: "e" + repeat!(decode_percent("%CC%81"), 2000)) ?? "too large""#,
+                result: Ok("too large"),
+            },
+            Example {
+                title: "already wide enough",
+                source: r#"pad_start!("hello", 3)"#,
+                result: Ok("hello"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        compile(arguments, Side::Start)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct PadEnd;
+
+impl Function for PadEnd {
+    fn identifier(&self) -> &'static str {
+        "pad_end"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        PARAMETERS
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "pad with spaces",
+                source: r#"pad_end!("42", 5) + "|""#,
+                result: Ok("42   |"),
+            },
+            Example {
+                title: "pad with a multi-character fill",
+                source: r#"pad_end!("ab", 7, fill: "-=")"#,
+                result: Ok("ab-=-=-"),
+            },
+            Example {
+                title: "width counts graphemes",
+                source: r#"pad_end!("héllo", 6, fill: ".")"#,
+                result: Ok("héllo."),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        compile(arguments, Side::End)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PadFn {
+    value: Box<dyn Expression>,
+    width: Box<dyn Expression>,
+    fill: Option<Box<dyn Expression>>,
+    side: Side,
+}
+
+impl FunctionExpression for PadFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let value = value.try_bytes_utf8_lossy()?;
+        let width = self.width.resolve(ctx)?.try_integer()?;
+        let fill = match &self.fill {
+            Some(fill) => fill.resolve(ctx)?.try_bytes_utf8_lossy()?.into_owned(),
+            None => " ".to_owned(),
+        };
+
+        pad(&value, width, &fill, self.side)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::bytes().fallible()
+    }
+}
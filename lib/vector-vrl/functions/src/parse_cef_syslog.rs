@@ -0,0 +1,332 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Datelike, Utc};
+use syslog_loose::{IncompleteDate, Message, ProcId, Protocol, Variant};
+use vrl::prelude::*;
+
+const CEF_HEADER_FIELDS: [&str; 7] = [
+    "cefVersion",
+    "deviceVendor",
+    "deviceProduct",
+    "deviceVersion",
+    "deviceEventClassId",
+    "name",
+    "severity",
+];
+
+/// Resolves the year for RFC 3164 timestamps, which don't include one.
+///
+/// A December message received in January is assumed to be from the previous year.
+fn resolve_year((month, _date, _hour, _min, _sec): IncompleteDate) -> i32 {
+    let now = Utc::now();
+    if now.month() == 1 && month == 12 {
+        now.year() - 1
+    } else {
+        now.year()
+    }
+}
+
+/// Builds the same object as `parse_syslog`.
+fn syslog_fields(parsed: Message<&str>) -> ObjectMap {
+    let mut fields = ObjectMap::new();
+
+    fields.insert("message".into(), parsed.msg.into());
+    if let Some(hostname) = parsed.hostname {
+        fields.insert("hostname".into(), hostname.into());
+    }
+    if let Some(severity) = parsed.severity {
+        fields.insert("severity".into(), severity.as_str().into());
+    }
+    if let Some(facility) = parsed.facility {
+        fields.insert("facility".into(), facility.as_str().into());
+    }
+    if let Protocol::RFC5424(version) = parsed.protocol {
+        fields.insert("version".into(), Value::Integer(version.into()));
+    }
+    if let Some(appname) = parsed.appname {
+        fields.insert("appname".into(), appname.into());
+    }
+    if let Some(msgid) = parsed.msgid {
+        fields.insert("msgid".into(), msgid.into());
+    }
+    if let Some(timestamp) = parsed.timestamp {
+        fields.insert("timestamp".into(), DateTime::<Utc>::from(timestamp).into());
+    }
+    if let Some(procid) = parsed.procid {
+        let procid = match procid {
+            ProcId::PID(pid) => pid.into(),
+            ProcId::Name(name) => name.into(),
+        };
+        fields.insert("procid".into(), procid);
+    }
+    for element in parsed.structured_data {
+        let params = element
+            .params()
+            .map(|(name, value)| (name.to_string().into(), value.into()))
+            .collect::<ObjectMap>();
+        fields.insert(element.id.to_string().into(), params.into());
+    }
+
+    fields
+}
+
+fn parse_syslog(value: &str, timezone: &TimeZone) -> Option<ObjectMap> {
+    let timezone = match timezone {
+        TimeZone::Local => None,
+        TimeZone::Named(tz) => Some(*tz),
+    };
+    let parsed = syslog_loose::parse_message_with_year_exact_tz(
+        value,
+        resolve_year,
+        timezone,
+        Variant::Either,
+    )
+    .ok()?;
+
+    Some(syslog_fields(parsed))
+}
+
+fn unescape(input: &str, extension: bool) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut chars = input.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            output.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some(c @ ('\\' | '|')) => output.push(c),
+            Some('=') if extension => output.push('='),
+            Some('n') if extension => output.push('\n'),
+            Some('r') if extension => output.push('\r'),
+            Some(c) => {
+                output.push('\\');
+                output.push(c);
+            }
+            None => output.push('\\'),
+        }
+    }
+
+    output
+}
+
+/// Returns the byte offsets of every occurrence of `target` not preceded by a backslash escape.
+fn unescaped_positions(input: &str, target: u8) -> Vec<usize> {
+    let mut positions = Vec::new();
+    let mut escaped = false;
+
+    for (i, b) in input.bytes().enumerate() {
+        match b {
+            _ if escaped => escaped = false,
+            b'\\' => escaped = true,
+            b if b == target => positions.push(i),
+            _ => {}
+        }
+    }
+
+    positions
+}
+
+fn parse_extension(extension: &str, fields: &mut ObjectMap) -> Result<(), String> {
+    let extension = extension.trim();
+    let equals = unescaped_positions(extension, b'=');
+
+    // Each key starts after the last space preceding its `=`; the previous value runs up to it.
+    let mut key_starts = Vec::with_capacity(equals.len());
+    let mut previous = 0;
+    for (i, &eq) in equals.iter().enumerate() {
+        let start = if i == 0 {
+            0
+        } else {
+            extension[previous..eq]
+                .rfind(' ')
+                .map(|space| previous + space + 1)
+                .ok_or_else(|| format!("malformed CEF extension near {:?}", &extension[eq..]))?
+        };
+        key_starts.push(start);
+        previous = eq + 1;
+    }
+
+    for (i, (&start, &eq)) in key_starts.iter().zip(&equals).enumerate() {
+        let key = &extension[start..eq];
+        if key.is_empty() || key.contains(char::is_whitespace) {
+            return Err(format!("malformed CEF extension key {key:?}"));
+        }
+        let end = key_starts.get(i + 1).copied().unwrap_or(extension.len());
+        let value = extension[eq + 1..end].trim_end();
+        fields.insert(key.into(), unescape(value, true).into());
+    }
+
+    if equals.is_empty() && !extension.is_empty() {
+        return Err(format!("malformed CEF extension {extension:?}"));
+    }
+
+    Ok(())
+}
+
+fn parse_cef(input: &str) -> Result<ObjectMap, String> {
+    let payload = input
+        .strip_prefix("CEF:")
+        .ok_or_else(|| "missing CEF prefix".to_owned())?;
+
+    let pipes = unescaped_positions(payload, b'|');
+    if pipes.len() < CEF_HEADER_FIELDS.len() {
+        return Err(format!(
+            "expected {} CEF header fields, found {}",
+            CEF_HEADER_FIELDS.len(),
+            pipes.len()
+        ));
+    }
+
+    let mut fields = ObjectMap::new();
+    let mut start = 0;
+    for (name, &end) in CEF_HEADER_FIELDS.iter().zip(&pipes) {
+        fields.insert((*name).into(), unescape(&payload[start..end], false).into());
+        start = end + 1;
+    }
+
+    parse_extension(&payload[start..], &mut fields)?;
+
+    Ok(fields)
+}
+
+fn parse_cef_syslog(value: &str, timezone: &TimeZone) -> Resolved {
+    let value = value.trim();
+
+    let syslog = parse_syslog(value, timezone);
+    let cef = match value.find("CEF:") {
+        Some(offset) => parse_cef(&value[offset..]),
+        None => Err("no CEF payload found".to_owned()),
+    };
+
+    let cef = match (syslog.is_some(), cef) {
+        (_, Ok(fields)) => Value::Object(fields),
+        (true, Err(_)) => Value::Null,
+        (false, Err(err)) => {
+            return Err(format!("unable to parse input as syslog-wrapped CEF: {err}").into())
+        }
+    };
+
+    Ok(Value::from(ObjectMap::from([
+        ("cef".into(), cef),
+        ("syslog".into(), syslog.map_or(Value::Null, Value::Object)),
+    ])))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseCefSyslog;
+
+impl Function for ParseCefSyslog {
+    fn identifier(&self) -> &'static str {
+        "parse_cef_syslog"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "firewall event in an RFC 5424 envelope",
+                source: r#"parse_cef_syslog!(s'<134>1 2024-02-12T10:15:00Z fw01 asa 4242 FW-DENY - CEF:0|Acme|Firewall|9.1|100|Connection denied|7|src=10.0.0.5 dst=192.168.1.10 dpt=443 act=deny msg=blocked by rule\=42')"#,
+                result: Ok(indoc! {r#"{
+                    "cef": {
+                        "cefVersion": "0",
+                        "deviceVendor": "Acme",
+                        "deviceProduct": "Firewall",
+                        "deviceVersion": "9.1",
+                        "deviceEventClassId": "100",
+                        "name": "Connection denied",
+                        "severity": "7",
+                        "src": "10.0.0.5",
+                        "dst": "192.168.1.10",
+                        "dpt": "443",
+                        "act": "deny",
+                        "msg": "blocked by rule=42"
+                    },
+                    "syslog": {
+                        "facility": "local0",
+                        "severity": "info",
+                        "timestamp": "2024-02-12T10:15:00Z",
+                        "hostname": "fw01",
+                        "appname": "asa",
+                        "procid": 4242,
+                        "msgid": "FW-DENY",
+                        "version": 1,
+                        "message": "CEF:0|Acme|Firewall|9.1|100|Connection denied|7|src=10.0.0.5 dst=192.168.1.10 dpt=443 act=deny msg=blocked by rule\\=42"
+                    }
+                }"#}),
+            },
+            Example {
+                title: "syslog extension doesn't collide with the envelope",
+                source: r#"parse_cef_syslog!(s'<134>1 2024-02-12T10:15:00Z fw01 asa - - - CEF:0|Acme|Firewall|9.1|100|Relayed|3|syslog=relay01').cef.syslog"#,
+                result: Ok(r#""relay01""#),
+            },
+            Example {
+                title: "bare CEF",
+                source: r#"parse_cef_syslog!("CEF:0|Acme|Firewall|9.1|200|Port scan|9|src=10.0.0.7")"#,
+                result: Ok(indoc! {r#"{
+                    "cef": {
+                        "cefVersion": "0",
+                        "deviceVendor": "Acme",
+                        "deviceProduct": "Firewall",
+                        "deviceVersion": "9.1",
+                        "deviceEventClassId": "200",
+                        "name": "Port scan",
+                        "severity": "9",
+                        "src": "10.0.0.7"
+                    },
+                    "syslog": null
+                }"#}),
+            },
+            Example {
+                title: "function call argument",
+                source: r#"parse_cef_syslog!(strip_whitespace("  CEF:0|Acme|Firewall|9.1|200|Port scan|9|src=10.0.0.7  ")).cef.src"#,
+                result: Ok(r#""10.0.0.7""#),
+            },
+            Example {
+                title: "neither syslog nor CEF",
+                source: r#"parse_cef_syslog("not a log line") ?? "invalid""#,
+                result: Ok("invalid"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(ParseCefSyslogFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseCefSyslogFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ParseCefSyslogFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        parse_cef_syslog(&value.try_bytes_utf8_lossy()?, ctx.timezone())
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::object(BTreeMap::from([
+            ("cef".into(), Kind::object(Collection::any()).or_null()),
+            ("syslog".into(), Kind::object(Collection::any()).or_null()),
+        ]))
+        .fallible()
+    }
+}
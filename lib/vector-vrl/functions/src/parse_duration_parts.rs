@@ -0,0 +1,201 @@
+use std::collections::BTreeMap;
+
+use vrl::prelude::*;
+
+const MILLISECOND: f64 = 1.0;
+const SECOND: f64 = 1_000.0 * MILLISECOND;
+const MINUTE: f64 = 60.0 * SECOND;
+const HOUR: f64 = 60.0 * MINUTE;
+const DAY: f64 = 24.0 * HOUR;
+const WEEK: f64 = 7.0 * DAY;
+
+/// Parses a compact duration such as `1h30m` or `2d 4h 10.5s` into milliseconds.
+fn parse_compact(input: &str) -> Option<f64> {
+    let mut rest = input.trim();
+    let mut total = 0.0;
+
+    if rest.is_empty() {
+        return None;
+    }
+
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let number = rest[..number_len].parse::<f64>().ok()?;
+        rest = rest[number_len..].trim_start();
+
+        let unit_len = rest
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(rest.len());
+        let unit = match &rest[..unit_len] {
+            "ns" => MILLISECOND / 1_000_000.0,
+            "us" | "µs" => MILLISECOND / 1_000.0,
+            "ms" => MILLISECOND,
+            "s" => SECOND,
+            "m" => MINUTE,
+            "h" => HOUR,
+            "d" => DAY,
+            "w" => WEEK,
+            _ => return None,
+        };
+        rest = rest[unit_len..].trim_start();
+
+        total += number * unit;
+    }
+
+    Some(total)
+}
+
+/// Parses an ISO 8601 duration such as `P1DT2H30M` into milliseconds.
+///
+/// Years and months are rejected, since their length depends on the date they are applied to.
+fn parse_iso8601(input: &str) -> Option<f64> {
+    let rest = input.strip_prefix('P')?;
+    let (date, time) = match rest.split_once('T') {
+        Some((_, "")) => return None,
+        Some((date, time)) => (date, Some(time)),
+        None => (rest, None),
+    };
+
+    if date.is_empty() && time.is_none() {
+        return None;
+    }
+
+    let mut total = 0.0;
+    for (designators, part) in [("WD", Some(date)), ("HMS", time)] {
+        let mut part = part.unwrap_or_default();
+        while !part.is_empty() {
+            let end = part.find(|c: char| c.is_ascii_alphabetic())?;
+            let number = part[..end].replace(',', ".").parse::<f64>().ok()?;
+            let designator = part[end..].chars().next()?;
+            if !designators.contains(designator) {
+                return None;
+            }
+            total += number
+                * match (designators, designator) {
+                    ("WD", 'W') => WEEK,
+                    ("WD", _) => DAY,
+                    (_, 'H') => HOUR,
+                    (_, 'M') => MINUTE,
+                    _ => SECOND,
+                };
+            part = &part[end + 1..];
+        }
+    }
+
+    Some(total)
+}
+
+fn parse_duration_parts(value: &str) -> Resolved {
+    let value = value.trim();
+    let milliseconds = if value.starts_with('P') {
+        parse_iso8601(value)
+    } else {
+        parse_compact(value)
+    }
+    .filter(|milliseconds| milliseconds.is_finite() && *milliseconds <= i64::MAX as f64)
+    .ok_or_else(|| format!("unable to parse duration: {value:?}"))?;
+
+    let mut remaining = milliseconds.round() as i64;
+    let mut take = |unit: f64| {
+        let unit = unit as i64;
+        let count = remaining / unit;
+        remaining %= unit;
+        Value::Integer(count)
+    };
+
+    Ok(Value::Object(ObjectMap::from([
+        ("days".into(), take(DAY)),
+        ("hours".into(), take(HOUR)),
+        ("minutes".into(), take(MINUTE)),
+        ("seconds".into(), take(SECOND)),
+        ("milliseconds".into(), take(MILLISECOND)),
+    ])))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseDurationParts;
+
+impl Function for ParseDurationParts {
+    fn identifier(&self) -> &'static str {
+        "parse_duration_parts"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "compact duration",
+                source: r#"parse_duration_parts!("1h30m")"#,
+                result: Ok(
+                    r#"{"days": 0, "hours": 1, "minutes": 30, "seconds": 0, "milliseconds": 0}"#,
+                ),
+            },
+            Example {
+                title: "mixed units overflowing into larger ones",
+                source: r#"parse_duration_parts!("1d 25h 90m 61.5s")"#,
+                result: Ok(
+                    r#"{"days": 2, "hours": 2, "minutes": 31, "seconds": 1, "milliseconds": 500}"#,
+                ),
+            },
+            Example {
+                title: "ISO 8601 duration",
+                source: r#"parse_duration_parts!("P1DT2H3M4.005S")"#,
+                result: Ok(
+                    r#"{"days": 1, "hours": 2, "minutes": 3, "seconds": 4, "milliseconds": 5}"#,
+                ),
+            },
+            Example {
+                title: "malformed duration",
+                source: r#"parse_duration_parts("1 fortnight") ?? "invalid""#,
+                result: Ok("invalid"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(ParseDurationPartsFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseDurationPartsFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ParseDurationPartsFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        parse_duration_parts(&value.try_bytes_utf8_lossy()?)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::object(inner_kind()).fallible()
+    }
+}
+
+fn inner_kind() -> BTreeMap<Field, Kind> {
+    BTreeMap::from([
+        ("days".into(), Kind::integer()),
+        ("hours".into(), Kind::integer()),
+        ("minutes".into(), Kind::integer()),
+        ("seconds".into(), Kind::integer()),
+        ("milliseconds".into(), Kind::integer()),
+    ])
+}
@@ -0,0 +1,160 @@
+use std::collections::BTreeMap;
+
+use vrl::prelude::*;
+
+fn parse_ini(value: Value, lowercase_keys: bool) -> Resolved {
+    let bytes = value.try_bytes()?;
+    let input = String::from_utf8_lossy(&bytes);
+    let key = |key: &str| -> KeyString {
+        if lowercase_keys {
+            key.to_lowercase().into()
+        } else {
+            key.into()
+        }
+    };
+
+    let mut result = ObjectMap::new();
+    let mut sections: BTreeMap<KeyString, ObjectMap> = BTreeMap::new();
+    let mut section = None;
+
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with(';') || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('[') {
+            let name = header
+                .strip_suffix(']')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .ok_or_else(|| format!("invalid section header on line {}", index + 1))?;
+            let name = key(name);
+            sections.entry(name.clone()).or_default();
+            section = Some(name);
+            continue;
+        }
+
+        let (name, value) = line
+            .split_once('=')
+            .map(|(name, value)| (name.trim(), value.trim()))
+            .filter(|(name, _)| !name.is_empty())
+            .ok_or_else(|| format!("expected `key = value` on line {}", index + 1))?;
+
+        let entries = match &section {
+            Some(section) => sections.entry(section.clone()).or_default(),
+            None => &mut result,
+        };
+        entries.insert(key(name), value.into());
+    }
+
+    // Sections take precedence over top-level keys of the same name.
+    result.extend(
+        sections
+            .into_iter()
+            .map(|(name, entries)| (name, Value::Object(entries))),
+    );
+
+    Ok(Value::Object(result))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseIni;
+
+impl Function for ParseIni {
+    fn identifier(&self) -> &'static str {
+        "parse_ini"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "lowercase_keys",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "sectioned",
+                source: r#"parse_ini!("[server]\nhost = example.com\nport = 8080\n\n[client]\nretries = 3")"#,
+                result: Ok(
+                    r#"{"client": {"retries": "3"}, "server": {"host": "example.com", "port": "8080"}}"#,
+                ),
+            },
+            Example {
+                title: "section-less",
+                source: r#"parse_ini!("name = app\nversion=1.2")"#,
+                result: Ok(r#"{"name": "app", "version": "1.2"}"#),
+            },
+            Example {
+                title: "top-level keys before a section",
+                source: r#"parse_ini!("name = app\n[server]\nhost = example.com")"#,
+                result: Ok(r#"{"name": "app", "server": {"host": "example.com"}}"#),
+            },
+            Example {
+                title: "comments",
+                source: r#"parse_ini!("; generated\n# do not edit\n[server]\n  ; the port\nport = 8080")"#,
+                result: Ok(r#"{"server": {"port": "8080"}}"#),
+            },
+            Example {
+                title: "lowercase keys",
+                source: r#"parse_ini!("[Server]\nHost = example.com", lowercase_keys: true)"#,
+                result: Ok(r#"{"server": {"host": "example.com"}}"#),
+            },
+            Example {
+                title: "invalid line",
+                source: r#"parse_ini!("[server]\nhost")"#,
+                result: Err(
+                    r#"function call error for "parse_ini" at (0:28): expected `key = value` on line 2"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let lowercase_keys = arguments.optional("lowercase_keys");
+
+        Ok(ParseIniFn {
+            value,
+            lowercase_keys,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseIniFn {
+    value: Box<dyn Expression>,
+    lowercase_keys: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for ParseIniFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let lowercase_keys = match &self.lowercase_keys {
+            Some(lowercase_keys) => lowercase_keys.resolve(ctx)?.try_boolean()?,
+            None => false,
+        };
+
+        parse_ini(value, lowercase_keys)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::object(Collection::any()).fallible()
+    }
+}
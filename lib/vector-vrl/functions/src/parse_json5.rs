@@ -0,0 +1,249 @@
+use std::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use vrl::prelude::*;
+
+/// Maximum nesting of arrays and objects. The JSON5 parser recurses once per level, so deeper
+/// input could overflow the stack.
+const MAX_DEPTH: usize = 128;
+
+const NON_FINITE: &str = "non-finite numbers are not supported";
+
+/// Scans the input for its nesting depth, skipping over strings and comments, so that deeply
+/// nested input is rejected before it reaches the recursive parser.
+fn check_depth(input: &[u8]) -> Result<(), ExpressionError> {
+    let mut depth = 0usize;
+    let mut index = 0;
+
+    while index < input.len() {
+        match input[index] {
+            quote @ (b'"' | b'\'') => {
+                index += 1;
+                while index < input.len() && input[index] != quote {
+                    if input[index] == b'\\' {
+                        index += 1;
+                    }
+                    index += 1;
+                }
+            }
+            b'/' if input.get(index + 1) == Some(&b'/') => {
+                while index < input.len() && input[index] != b'\n' {
+                    index += 1;
+                }
+            }
+            b'/' if input.get(index + 1) == Some(&b'*') => {
+                index += 2;
+                while index < input.len() && !input[index..].starts_with(b"*/") {
+                    index += 1;
+                }
+                index += 1;
+            }
+            b'[' | b'{' => {
+                depth += 1;
+                if depth > MAX_DEPTH {
+                    return Err(format!(
+                        "unable to parse json5: nesting exceeds the maximum depth of {MAX_DEPTH}"
+                    )
+                    .into());
+                }
+            }
+            b']' | b'}' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        index += 1;
+    }
+
+    Ok(())
+}
+
+/// A VRL value deserialized from JSON5, rejecting the non-finite numbers (`NaN`, `Infinity`) that
+/// JSON5 allows but VRL floats can't consistently represent.
+struct Json5Value(Value);
+
+impl<'de> Deserialize<'de> for Json5Value {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(Json5Visitor).map(Json5Value)
+    }
+}
+
+struct Json5Visitor;
+
+impl<'de> Visitor<'de> for Json5Visitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a JSON5 value")
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<Value, E> {
+        Ok(value.into())
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Value, E> {
+        Ok(value.into())
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Value, E> {
+        match i64::try_from(value) {
+            Ok(value) => Ok(value.into()),
+            Err(_) => self.visit_f64(value as f64),
+        }
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Value, E> {
+        if value.is_finite() {
+            Ok(Value::from_f64_or_zero(value))
+        } else {
+            Err(E::custom(NON_FINITE))
+        }
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Value, E> {
+        Ok(value.into())
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Value, E> {
+        Ok(value.into())
+    }
+
+    fn visit_unit<E>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let mut array = Vec::new();
+        while let Some(Json5Value(value)) = seq.next_element()? {
+            array.push(value);
+        }
+        Ok(Value::Array(array))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        let mut object = ObjectMap::new();
+        while let Some((key, Json5Value(value))) = map.next_entry::<String, _>()? {
+            object.insert(key.into(), value);
+        }
+        Ok(Value::Object(object))
+    }
+}
+
+fn parse_json5(value: Value) -> Resolved {
+    let bytes = value.try_bytes()?;
+    check_depth(&bytes)?;
+    let input = String::from_utf8_lossy(&bytes);
+
+    json5::from_str::<Json5Value>(&input)
+        .map(|Json5Value(value)| value)
+        .map_err(|err| {
+            // Syntax errors render a multi-line snippet of the input, so only report their
+            // position.
+            match err {
+                json5::Error::Message { msg, .. } if msg == NON_FINITE => {
+                    format!("unable to parse json5: {msg}")
+                }
+                json5::Error::Message {
+                    location: Some(location),
+                    ..
+                } => format!(
+                    "unable to parse json5: invalid syntax at line {}, column {}",
+                    location.line, location.column
+                ),
+                json5::Error::Message { msg, .. } => format!("unable to parse json5: {msg}"),
+            }
+            .into()
+        })
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseJson5;
+
+impl Function for ParseJson5 {
+    fn identifier(&self) -> &'static str {
+        "parse_json5"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "comments",
+                source: r#"parse_json5!("{ // the port\n\"port\": 8080 /* default */ }")"#,
+                result: Ok(r#"{"port": 8080}"#),
+            },
+            Example {
+                title: "trailing commas",
+                source: r#"parse_json5!("{\"hosts\": [\"a\", \"b\",],}")"#,
+                result: Ok(r#"{"hosts": ["a", "b"]}"#),
+            },
+            Example {
+                title: "unquoted keys and single quoted strings",
+                source: r#"parse_json5!("{name: 'app', ratio: .5, enabled: true}")"#,
+                result: Ok(r#"{"enabled": true, "name": "app", "ratio": 0.5}"#),
+            },
+            Example {
+                title: "standard JSON",
+                source: r#"parse_json5!("[1, \"two\", null]")"#,
+                result: Ok(r#"[1, "two", null]"#),
+            },
+            Example {
+                title: "non-finite numbers",
+                source: r#"parse_json5!("[NaN, Infinity]")"#,
+                result: Err(
+                    r#"function call error for "parse_json5" at (0:31): unable to parse json5: non-finite numbers are not supported"#,
+                ),
+            },
+            Example {
+                title: "nesting too deep",
+                source: r#"parse_json5!(repeat!("[", 200))"#,
+                result: Err(
+                    r#"function call error for "parse_json5" at (0:31): unable to parse json5: nesting exceeds the maximum depth of 128"#,
+                ),
+            },
+            Example {
+                title: "malformed input",
+                source: r#"parse_json5!("{name: }")"#,
+                result: Err(
+                    r#"function call error for "parse_json5" at (0:24): unable to parse json5: invalid syntax at line 1, column 8"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(ParseJson5Fn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseJson5Fn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ParseJson5Fn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        parse_json5(value)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::any().fallible()
+    }
+}
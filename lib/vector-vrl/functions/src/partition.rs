@@ -0,0 +1,128 @@
+use vrl::prelude::*;
+
+fn partition<T>(value: Vec<Value>, ctx: &mut Context, runner: closure::Runner<T>) -> Resolved
+where
+    T: Fn(&mut Context) -> Resolved,
+{
+    let mut matching = Vec::new();
+    let mut non_matching = Vec::new();
+
+    for (index, value) in value.into_iter().enumerate() {
+        let matched = runner
+            .run_index_value(ctx, index, &value)?
+            .as_boolean()
+            .expect("compiler guarantees boolean return type");
+
+        if matched {
+            matching.push(value);
+        } else {
+            non_matching.push(value);
+        }
+    }
+
+    Ok(Value::Array(vec![matching.into(), non_matching.into()]))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Partition;
+
+impl Function for Partition {
+    fn identifier(&self) -> &'static str {
+        "partition"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::ARRAY,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "partition by value",
+                source: "partition([1, 5, 2, 8, 3]) -> |_index, value| { value > 2 }",
+                result: Ok("[[5, 8, 3], [1, 2]]"),
+            },
+            Example {
+                title: "partition by index",
+                source: r#"partition(["a", "b", "c", "d"]) -> |index, _value| { mod(index, 2) == 0 }"#,
+                result: Ok(r#"[["a", "c"], ["b", "d"]]"#),
+            },
+            Example {
+                title: "nothing matches",
+                source: r#"partition(["a", "b"]) -> |_index, value| { value == "z" }"#,
+                result: Ok(r#"[[], ["a", "b"]]"#),
+            },
+            Example {
+                title: "empty array",
+                source: "partition([]) -> |_index, value| { value == 1 }",
+                result: Ok("[[], []]"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let closure = arguments.required_closure()?;
+
+        Ok(PartitionFn { value, closure }.as_expr())
+    }
+
+    fn closure(&self) -> Option<closure::Definition> {
+        use closure::{Definition, Input, Output, Variable, VariableKind};
+
+        Some(Definition {
+            inputs: vec![Input {
+                parameter_keyword: "value",
+                kind: Kind::array(Collection::any()),
+                variables: vec![
+                    Variable {
+                        kind: VariableKind::TargetInnerKey,
+                    },
+                    Variable {
+                        kind: VariableKind::TargetInnerValue,
+                    },
+                ],
+                output: Output::Kind(Kind::boolean()),
+                example: Example {
+                    title: "partition by value",
+                    source: "partition([1, 5, 2]) -> |_index, value| { value > 2 }",
+                    result: Ok("[[5], [1, 2]]"),
+                },
+            }],
+            is_iterator: true,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PartitionFn {
+    value: Box<dyn Expression>,
+    closure: FunctionClosure,
+}
+
+impl FunctionExpression for PartitionFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_array()?;
+        let FunctionClosure {
+            variables,
+            block,
+            block_type_def: _,
+        } = &self.closure;
+        let runner = closure::Runner::new(variables, |ctx| block.resolve(ctx));
+
+        partition(value, ctx, runner)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::array(Collection::from_unknown(Kind::array(Collection::any())))
+    }
+}
@@ -0,0 +1,175 @@
+use vrl::path::{OwnedSegment, OwnedValuePath};
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+enum Selection {
+    Pick,
+    Omit,
+}
+
+/// Converts each key into a path, splitting it on `.` to address nested fields.
+fn key_paths(keys: Vec<Value>) -> Result<Vec<OwnedValuePath>, ExpressionError> {
+    keys.into_iter()
+        .map(|key| {
+            let key = key.try_bytes_utf8_lossy()?.into_owned();
+            let mut path = OwnedValuePath::root();
+            for field in key.split('.') {
+                path.push(OwnedSegment::field(field));
+            }
+            Ok(path)
+        })
+        .collect()
+}
+
+fn select_keys(value: ObjectMap, keys: Vec<Value>, selection: Selection) -> Resolved {
+    let paths = key_paths(keys)?;
+    let value = Value::Object(value);
+
+    Ok(match selection {
+        Selection::Pick => {
+            let mut result = Value::Object(ObjectMap::new());
+            for path in &paths {
+                if let Some(found) = value.get(path) {
+                    result.insert(path, found.clone());
+                }
+            }
+            result
+        }
+        Selection::Omit => {
+            let mut result = value;
+            for path in &paths {
+                result.remove(path, false);
+            }
+            result
+        }
+    })
+}
+
+const PARAMETERS: &[Parameter] = &[
+    Parameter {
+        keyword: "value",
+        kind: kind::OBJECT,
+        required: true,
+    },
+    Parameter {
+        keyword: "keys",
+        kind: kind::ARRAY,
+        required: true,
+    },
+];
+
+fn compile(arguments: ArgumentList, selection: Selection) -> Compiled {
+    let value = arguments.required("value");
+    let keys = arguments.required("keys");
+
+    Ok(SelectKeysFn {
+        value,
+        keys,
+        selection,
+    }
+    .as_expr())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Pick;
+
+impl Function for Pick {
+    fn identifier(&self) -> &'static str {
+        "pick"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        PARAMETERS
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "top-level keys",
+                source: r#"pick!({"host": "web-1", "message": "hi", "level": "info"}, ["host", "level"])"#,
+                result: Ok(r#"{"host": "web-1", "level": "info"}"#),
+            },
+            Example {
+                title: "nested path",
+                source: r#"pick!({"http": {"status": 200, "method": "GET"}, "message": "hi"}, ["http.status"])"#,
+                result: Ok(r#"{"http": {"status": 200}}"#),
+            },
+            Example {
+                title: "missing keys are ignored",
+                source: r#"pick!({"host": "web-1"}, ["host", "user.id"])"#,
+                result: Ok(r#"{"host": "web-1"}"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        compile(arguments, Selection::Pick)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Omit;
+
+impl Function for Omit {
+    fn identifier(&self) -> &'static str {
+        "omit"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        PARAMETERS
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "top-level keys",
+                source: r#"omit!({"host": "web-1", "message": "hi", "level": "info"}, ["host", "level"])"#,
+                result: Ok(r#"{"message": "hi"}"#),
+            },
+            Example {
+                title: "nested path",
+                source: r#"omit!({"http": {"status": 200, "method": "GET"}, "message": "hi"}, ["http.method"])"#,
+                result: Ok(r#"{"http": {"status": 200}, "message": "hi"}"#),
+            },
+            Example {
+                title: "missing keys are ignored",
+                source: r#"omit!({"host": "web-1"}, ["level", "user.id"])"#,
+                result: Ok(r#"{"host": "web-1"}"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        compile(arguments, Selection::Omit)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SelectKeysFn {
+    value: Box<dyn Expression>,
+    keys: Box<dyn Expression>,
+    selection: Selection,
+}
+
+impl FunctionExpression for SelectKeysFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_object()?;
+        let keys = self.keys.resolve(ctx)?.try_array()?;
+
+        select_keys(value, keys, self.selection)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::object(Collection::any()).fallible()
+    }
+}
@@ -0,0 +1,130 @@
+use vrl::prelude::*;
+
+fn rename_keys(value: ObjectMap, mapping: &ObjectMap, recursive: bool) -> Resolved {
+    let mut renamed = ObjectMap::new();
+
+    for (key, value) in value {
+        let value = match value {
+            Value::Object(object) if recursive => rename_keys(object, mapping, recursive)?,
+            Value::Array(array) if recursive => Value::Array(
+                array
+                    .into_iter()
+                    .map(|value| match value {
+                        Value::Object(object) => rename_keys(object, mapping, recursive),
+                        value => Ok(value),
+                    })
+                    .collect::<ExpressionResult<Vec<_>>>()?,
+            ),
+            value => value,
+        };
+
+        let new_key = match mapping.get(&key) {
+            Some(new_key) => KeyString::from(new_key.try_bytes_utf8_lossy()?.into_owned()),
+            None => key.clone(),
+        };
+
+        if renamed.contains_key(&new_key) {
+            return Err(format!(
+                "renaming key {:?} to {:?} collides with an existing key",
+                key.as_str(),
+                new_key.as_str()
+            )
+            .into());
+        }
+
+        renamed.insert(new_key, value);
+    }
+
+    Ok(Value::Object(renamed))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct RenameKeys;
+
+impl Function for RenameKeys {
+    fn identifier(&self) -> &'static str {
+        "rename_keys"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::OBJECT,
+                required: true,
+            },
+            Parameter {
+                keyword: "mapping",
+                kind: kind::OBJECT,
+                required: true,
+            },
+            Parameter {
+                keyword: "recursive",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "rename keys",
+                source: r#"rename_keys!({"msg": "hello", "lvl": "info", "host": "a"}, {"msg": "message", "lvl": "level"})"#,
+                result: Ok(r#"{"host": "a", "level": "info", "message": "hello"}"#),
+            },
+            Example {
+                title: "rename keys recursively",
+                source: r#"rename_keys!({"msg": "hello", "nested": {"msg": "world"}}, {"msg": "message"}, recursive: true)"#,
+                result: Ok(r#"{"message": "hello", "nested": {"message": "world"}}"#),
+            },
+            Example {
+                title: "rename to an existing key",
+                source: r#"rename_keys({"msg": "hello", "message": "world"}, {"msg": "message"}) ?? "collision""#,
+                result: Ok("collision"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let mapping = arguments.required("mapping");
+        let recursive = arguments.optional("recursive");
+
+        Ok(RenameKeysFn {
+            value,
+            mapping,
+            recursive,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RenameKeysFn {
+    value: Box<dyn Expression>,
+    mapping: Box<dyn Expression>,
+    recursive: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for RenameKeysFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_object()?;
+        let mapping = self.mapping.resolve(ctx)?.try_object()?;
+        let recursive = match &self.recursive {
+            Some(recursive) => recursive.resolve(ctx)?.try_boolean()?,
+            None => false,
+        };
+
+        rename_keys(value, &mapping, recursive)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::object(Collection::any()).fallible()
+    }
+}
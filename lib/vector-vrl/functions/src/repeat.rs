@@ -0,0 +1,99 @@
+use vrl::prelude::*;
+
+/// Upper bound on the size of the repeated string, to prevent giant allocations from untrusted
+/// input. This is measured in bytes, as a single grapheme can be arbitrarily large.
+const MAX_BYTES: usize = 1024 * 1024;
+
+fn repeat(value: &[u8], count: i64) -> Resolved {
+    let count = usize::try_from(count).map_err(|_| "count must not be negative")?;
+
+    match value.len().checked_mul(count) {
+        Some(size) if size <= MAX_BYTES => Ok(Value::Bytes(value.repeat(count).into())),
+        _ => Err(format!("repeated string would exceed the limit of {MAX_BYTES} bytes").into()),
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Repeat;
+
+impl Function for Repeat {
+    fn identifier(&self) -> &'static str {
+        "repeat"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "count",
+                kind: kind::INTEGER,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "repeat a string",
+                source: r#"repeat!("ab", 3)"#,
+                result: Ok("ababab"),
+            },
+            Example {
+                title: "repeat zero times",
+                source: r#"length(repeat!("ab", 0))"#,
+                result: Ok("0"),
+            },
+            Example {
+                title: "repeat a multibyte string",
+                source: r#"repeat!("é👍🏽", 2)"#,
+                result: Ok("é👍🏽é👍🏽"),
+            },
+            Example {
+                title: "a multi-kilobyte grapheme beyond the size limit",
+                source: r#"repeat("e" + repeat!(decode_percent("%CC%81"), 2000), 1000) ?? "too large""#,
+                result: Ok("too large"),
+            },
+            Example {
+                title: "repeat beyond the size limit",
+                source: r#"repeat("ab", 10000000) ?? "too large""#,
+                result: Ok("too large"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let count = arguments.required("count");
+
+        Ok(RepeatFn { value, count }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RepeatFn {
+    value: Box<dyn Expression>,
+    count: Box<dyn Expression>,
+}
+
+impl FunctionExpression for RepeatFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_bytes()?;
+        let count = self.count.resolve(ctx)?.try_integer()?;
+
+        repeat(&value, count)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::bytes().fallible()
+    }
+}
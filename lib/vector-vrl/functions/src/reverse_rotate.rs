@@ -0,0 +1,163 @@
+use vrl::prelude::*;
+
+fn reverse(mut value: Vec<Value>) -> Value {
+    value.reverse();
+    Value::Array(value)
+}
+
+/// Rotates right by `count` positions, or left when `count` is negative, wrapping around the
+/// length of the array.
+fn rotate(mut value: Vec<Value>, count: i64) -> Value {
+    if !value.is_empty() {
+        // `rem_euclid` maps a left rotation onto the equivalent right rotation.
+        let count = count.rem_euclid(value.len() as i64) as usize;
+        value.rotate_right(count);
+    }
+
+    Value::Array(value)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Reverse;
+
+impl Function for Reverse {
+    fn identifier(&self) -> &'static str {
+        "reverse"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::ARRAY,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "reverse",
+                source: r#"reverse([1, "two", true])"#,
+                result: Ok(r#"[true, "two", 1]"#),
+            },
+            Example {
+                title: "empty array",
+                source: "reverse([])",
+                result: Ok("[]"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(ReverseFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ReverseFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ReverseFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_array()?;
+
+        Ok(reverse(value))
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::array(Collection::any()).infallible()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Rotate;
+
+impl Function for Rotate {
+    fn identifier(&self) -> &'static str {
+        "rotate"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "n",
+                kind: kind::INTEGER,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "rotate right",
+                source: "rotate([1, 2, 3, 4], 1)",
+                result: Ok("[4, 1, 2, 3]"),
+            },
+            Example {
+                title: "rotate left",
+                source: "rotate([1, 2, 3, 4], -1)",
+                result: Ok("[2, 3, 4, 1]"),
+            },
+            Example {
+                title: "rotate right past the length",
+                source: "rotate([1, 2, 3, 4], 6)",
+                result: Ok("[3, 4, 1, 2]"),
+            },
+            Example {
+                title: "rotate left past the length",
+                source: "rotate([1, 2, 3, 4], -5)",
+                result: Ok("[2, 3, 4, 1]"),
+            },
+            Example {
+                title: "empty array",
+                source: "rotate([], 3)",
+                result: Ok("[]"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let count = arguments.required("n");
+
+        Ok(RotateFn { value, count }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RotateFn {
+    value: Box<dyn Expression>,
+    count: Box<dyn Expression>,
+}
+
+impl FunctionExpression for RotateFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_array()?;
+        let count = self.count.resolve(ctx)?.try_integer()?;
+
+        Ok(rotate(value, count))
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::array(Collection::any()).infallible()
+    }
+}
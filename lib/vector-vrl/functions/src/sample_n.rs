@@ -0,0 +1,143 @@
+use rand::{thread_rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use vrl::prelude::*;
+
+/// Builds the generator for a seeded sample.
+///
+/// ChaCha8 produces the same stream on every platform and across `rand_chacha` versions, and the
+/// seed is laid out here rather than with `seed_from_u64`, so a seed keeps selecting the same
+/// sample.
+fn seeded_rng(seed: i64) -> ChaCha8Rng {
+    let mut bytes = [0; 32];
+    bytes[..8].copy_from_slice(&seed.to_le_bytes());
+    ChaCha8Rng::from_seed(bytes)
+}
+
+/// Returns a uniformly distributed index below `bound`, rejecting the values that would bias
+/// the modulo.
+fn index_below(rng: &mut impl RngCore, bound: usize) -> usize {
+    let bound = bound as u64;
+    let zone = u64::MAX - u64::MAX % bound;
+    loop {
+        let x = rng.next_u64();
+        if x < zone {
+            return (x % bound) as usize;
+        }
+    }
+}
+
+/// Moves up to `n` randomly chosen elements to the front of `values`, in random order.
+///
+/// This is a partial Fisher-Yates shuffle, kept here rather than using `rand`'s so that seeded
+/// samples don't change when `rand` changes its algorithm.
+fn partial_shuffle(values: &mut [Value], n: usize, rng: &mut impl RngCore) -> usize {
+    let n = n.min(values.len());
+    for i in 0..n {
+        let j = i + index_below(rng, values.len() - i);
+        values.swap(i, j);
+    }
+    n
+}
+
+fn sample_n(mut value: Vec<Value>, n: i64, seed: Option<i64>) -> Resolved {
+    let n = usize::try_from(n).map_err(|_| format!("n must be a non-negative integer, got {n}"))?;
+
+    let n = match seed {
+        Some(seed) => partial_shuffle(&mut value, n, &mut seeded_rng(seed)),
+        None => partial_shuffle(&mut value, n, &mut thread_rng()),
+    };
+    value.truncate(n);
+
+    Ok(Value::Array(value))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SampleN;
+
+impl Function for SampleN {
+    fn identifier(&self) -> &'static str {
+        "sample_n"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "n",
+                kind: kind::INTEGER,
+                required: true,
+            },
+            Parameter {
+                keyword: "seed",
+                kind: kind::INTEGER,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "sample size",
+                source: r#"length(sample_n!([1, 2, 3, 4, 5, 6, 7, 8], 3))"#,
+                result: Ok("3"),
+            },
+            Example {
+                title: "deterministic with a seed",
+                source: r#"sample_n!([1, 2, 3, 4, 5, 6, 7, 8], 3, seed: 7) == sample_n!([1, 2, 3, 4, 5, 6, 7, 8], 3, seed: 7)"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "seeded sample",
+                source: r#"sample_n!([1, 2, 3, 4, 5, 6, 7, 8], 3, seed: 7)"#,
+                result: Ok("[4, 2, 5]"),
+            },
+            Example {
+                title: "n larger than the array",
+                source: r#"length(sample_n!(["a", "b", "c"], 10))"#,
+                result: Ok("3"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let n = arguments.required("n");
+        let seed = arguments.optional("seed");
+
+        Ok(SampleNFn { value, n, seed }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SampleNFn {
+    value: Box<dyn Expression>,
+    n: Box<dyn Expression>,
+    seed: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for SampleNFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_array()?;
+        let n = self.n.resolve(ctx)?.try_integer()?;
+        let seed = match &self.seed {
+            Some(seed) => Some(seed.resolve(ctx)?.try_integer()?),
+            None => None,
+        };
+
+        sample_n(value, n, seed)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::array(Collection::any()).fallible()
+    }
+}
@@ -0,0 +1,198 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+enum Operation {
+    Intersection,
+    Union,
+    Difference,
+}
+
+/// Applies the set operation to both arrays, keeping the elements in order of first appearance.
+///
+/// `Value` isn't hashable, so membership is checked linearly; the arrays being compared are
+/// expected to be small, such as the tags of an event.
+fn set_operation(a: Vec<Value>, b: Vec<Value>, operation: Operation) -> Value {
+    let mut result: Vec<Value> = Vec::new();
+    let push = |item: Value| {
+        if !result.contains(&item) {
+            result.push(item);
+        }
+    };
+
+    match operation {
+        Operation::Intersection => a.into_iter().filter(|item| b.contains(item)).for_each(push),
+        Operation::Union => a.into_iter().chain(b).for_each(push),
+        Operation::Difference => a
+            .into_iter()
+            .filter(|item| !b.contains(item))
+            .for_each(push),
+    }
+
+    Value::Array(result)
+}
+
+const PARAMETERS: &[Parameter] = &[
+    Parameter {
+        keyword: "a",
+        kind: kind::ARRAY,
+        required: true,
+    },
+    Parameter {
+        keyword: "b",
+        kind: kind::ARRAY,
+        required: true,
+    },
+];
+
+fn compile(arguments: ArgumentList, operation: Operation) -> Compiled {
+    let a = arguments.required("a");
+    let b = arguments.required("b");
+
+    Ok(SetOperationFn { a, b, operation }.as_expr())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SetIntersection;
+
+impl Function for SetIntersection {
+    fn identifier(&self) -> &'static str {
+        "set_intersection"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        PARAMETERS
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "overlapping arrays",
+                source: r#"set_intersection(["web", "prod", "eu"], ["eu", "prod", "canary"])"#,
+                result: Ok(r#"["prod", "eu"]"#),
+            },
+            Example {
+                title: "disjoint arrays",
+                source: r#"set_intersection(["web", "prod"], ["db", "staging"])"#,
+                result: Ok("[]"),
+            },
+            Example {
+                title: "duplicates are removed",
+                source: r#"set_intersection([1, 2, 2, 3, 1], [2, 1, 1])"#,
+                result: Ok("[1, 2]"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        compile(arguments, Operation::Intersection)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SetUnion;
+
+impl Function for SetUnion {
+    fn identifier(&self) -> &'static str {
+        "set_union"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        PARAMETERS
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "overlapping arrays",
+                source: r#"set_union(["web", "prod", "eu"], ["eu", "prod", "canary"])"#,
+                result: Ok(r#"["web", "prod", "eu", "canary"]"#),
+            },
+            Example {
+                title: "disjoint arrays",
+                source: r#"set_union(["web", "prod"], ["db", "staging"])"#,
+                result: Ok(r#"["web", "prod", "db", "staging"]"#),
+            },
+            Example {
+                title: "duplicates are removed",
+                source: r#"set_union([1, 2, 2, 3, 1], [4, 3, 4])"#,
+                result: Ok("[1, 2, 3, 4]"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        compile(arguments, Operation::Union)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SetDifference;
+
+impl Function for SetDifference {
+    fn identifier(&self) -> &'static str {
+        "set_difference"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        PARAMETERS
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "overlapping arrays",
+                source: r#"set_difference(["web", "prod", "eu"], ["eu", "prod", "canary"])"#,
+                result: Ok(r#"["web"]"#),
+            },
+            Example {
+                title: "disjoint arrays",
+                source: r#"set_difference(["web", "prod"], ["db", "staging"])"#,
+                result: Ok(r#"["web", "prod"]"#),
+            },
+            Example {
+                title: "duplicates are removed",
+                source: r#"set_difference([1, 2, 2, 3, 1, 4], [3])"#,
+                result: Ok("[1, 2, 4]"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        compile(arguments, Operation::Difference)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SetOperationFn {
+    a: Box<dyn Expression>,
+    b: Box<dyn Expression>,
+    operation: Operation,
+}
+
+impl FunctionExpression for SetOperationFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let a = self.a.resolve(ctx)?.try_array()?;
+        let b = self.b.resolve(ctx)?.try_array()?;
+
+        Ok(set_operation(a, b, self.operation))
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::array(Collection::any()).infallible()
+    }
+}
@@ -0,0 +1,178 @@
+use syslog_loose::decompose_pri;
+use vrl::prelude::*;
+
+/// Looks up a facility code by name, using the same names as `parse_syslog` so that the two
+/// functions agree on every priority value.
+fn facility_by_name(name: &str) -> Option<i64> {
+    (0..=23u8)
+        .find(|code| {
+            decompose_pri(code << 3)
+                .0
+                .is_some_and(|facility| facility.as_str() == name)
+        })
+        .map(i64::from)
+}
+
+fn facility_code(value: Value) -> Result<i64, ExpressionError> {
+    let code = match value {
+        Value::Integer(code) => code,
+        Value::Bytes(name) => {
+            let name = String::from_utf8_lossy(&name);
+            facility_by_name(&name).ok_or_else(|| format!("unknown syslog facility {name:?}"))?
+        }
+        value => {
+            return Err(ValueError::Expected {
+                got: value.kind(),
+                expected: Kind::integer() | Kind::bytes(),
+            }
+            .into())
+        }
+    };
+
+    if !(0..=23).contains(&code) {
+        return Err(format!("syslog facility must be between 0 and 23, got {code}").into());
+    }
+
+    Ok(code)
+}
+
+fn severity_code(value: Value) -> Result<i64, ExpressionError> {
+    let code = match value {
+        Value::Integer(code) => code,
+        Value::Bytes(name) => match String::from_utf8_lossy(&name).as_ref() {
+            "emerg" | "panic" => 0,
+            "alert" => 1,
+            "crit" => 2,
+            "err" | "error" => 3,
+            "warning" | "warn" => 4,
+            "notice" => 5,
+            "info" => 6,
+            "debug" => 7,
+            name => return Err(format!("unknown syslog severity {name:?}").into()),
+        },
+        value => {
+            return Err(ValueError::Expected {
+                got: value.kind(),
+                expected: Kind::integer() | Kind::bytes(),
+            }
+            .into())
+        }
+    };
+
+    if !(0..=7).contains(&code) {
+        return Err(format!("syslog severity must be between 0 and 7, got {code}").into());
+    }
+
+    Ok(code)
+}
+
+fn syslog_priority(facility: Value, severity: Value) -> Resolved {
+    Ok(Value::Integer(
+        facility_code(facility)? * 8 + severity_code(severity)?,
+    ))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SyslogPriority;
+
+impl Function for SyslogPriority {
+    fn identifier(&self) -> &'static str {
+        "syslog_priority"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "facility",
+                kind: kind::INTEGER | kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "severity",
+                kind: kind::INTEGER | kind::BYTES,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "names",
+                source: r#"syslog_priority!("local0", "info")"#,
+                result: Ok("134"),
+            },
+            Example {
+                title: "codes",
+                source: r#"syslog_priority!(4, 2)"#,
+                result: Ok("34"),
+            },
+            Example {
+                title: "severity aliases",
+                source: r#"syslog_priority!("kern", "panic")"#,
+                result: Ok("0"),
+            },
+            Example {
+                title: "audit facility",
+                source: r#"syslog_priority!("audit", "info")"#,
+                result: Ok("110"),
+            },
+            Example {
+                title: "alert facility",
+                source: r#"syslog_priority!("alert", "info")"#,
+                result: Ok("118"),
+            },
+            Example {
+                title: "clockd facility",
+                source: r#"syslog_priority!("clockd", "info")"#,
+                result: Ok("126"),
+            },
+            Example {
+                title: "agrees with parse_syslog",
+                source: r#"parse_syslog!("<118>1 2020-03-13T20:45:38.119Z host app - - - msg").facility == "alert" && syslog_priority!("alert", "info") == 118"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "highest priority value",
+                source: r#"syslog_priority!("local7", "debug")"#,
+                result: Ok("191"),
+            },
+            Example {
+                title: "out of range",
+                source: r#"syslog_priority(24, 0) ?? "invalid""#,
+                result: Ok("invalid"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let facility = arguments.required("facility");
+        let severity = arguments.required("severity");
+
+        Ok(SyslogPriorityFn { facility, severity }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SyslogPriorityFn {
+    facility: Box<dyn Expression>,
+    severity: Box<dyn Expression>,
+}
+
+impl FunctionExpression for SyslogPriorityFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let facility = self.facility.resolve(ctx)?;
+        let severity = self.severity.resolve(ctx)?;
+
+        syslog_priority(facility, severity)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::integer().fallible()
+    }
+}
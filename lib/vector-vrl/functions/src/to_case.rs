@@ -0,0 +1,198 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+enum Case {
+    Snake,
+    Camel,
+    Pascal,
+    Kebab,
+    ScreamingSnake,
+}
+
+impl Case {
+    fn all_value() -> Vec<Value> {
+        ["snake", "camel", "pascal", "kebab", "screaming_snake"]
+            .into_iter()
+            .map(Value::from)
+            .collect()
+    }
+
+    fn from_value(value: &Value) -> Self {
+        match value
+            .try_bytes_utf8_lossy()
+            .expect("target not bytes")
+            .as_ref()
+        {
+            "snake" => Self::Snake,
+            "camel" => Self::Camel,
+            "pascal" => Self::Pascal,
+            "kebab" => Self::Kebab,
+            "screaming_snake" => Self::ScreamingSnake,
+            _ => unreachable!("enum invariant"),
+        }
+    }
+}
+
+/// Splits a string into words on any non-alphanumeric separator and on case boundaries.
+///
+/// A new word starts at an uppercase letter that follows a lowercase letter or digit
+/// (`fooBar`), or that is followed by a lowercase letter after an uppercase run, so acronyms stay
+/// together (`HTTPRequest` is `HTTP` and `Request`). Digits stay with the word before them.
+fn split_words(value: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let chars: Vec<char> = value.chars().collect();
+
+    for (index, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+
+        if c.is_uppercase() && !word.is_empty() {
+            let prev = chars[index - 1];
+            let next_is_lower = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+            if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_is_lower) {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+
+        word.push(c);
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+fn to_case(value: &str, case: Case) -> String {
+    let words = split_words(value);
+
+    match case {
+        Case::Snake => words.join("_").to_lowercase(),
+        Case::Kebab => words.join("-").to_lowercase(),
+        Case::ScreamingSnake => words.join("_").to_uppercase(),
+        Case::Pascal => words.iter().map(|word| capitalize(word)).collect(),
+        Case::Camel => words
+            .iter()
+            .enumerate()
+            .map(|(index, word)| {
+                if index == 0 {
+                    word.to_lowercase()
+                } else {
+                    capitalize(word)
+                }
+            })
+            .collect(),
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ToCase;
+
+impl Function for ToCase {
+    fn identifier(&self) -> &'static str {
+        "to_case"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "target",
+                kind: kind::BYTES,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "camelCase to snake",
+                source: r#"to_case("userId", "snake")"#,
+                result: Ok("user_id"),
+            },
+            Example {
+                title: "acronyms",
+                source: r#"to_case("HTTPRequest", "snake")"#,
+                result: Ok("http_request"),
+            },
+            Example {
+                title: "numbers",
+                source: r#"to_case("ipv4Address", "kebab")"#,
+                result: Ok("ipv4-address"),
+            },
+            Example {
+                title: "separators to camel",
+                source: r#"to_case("user-agent string", "camel")"#,
+                result: Ok("userAgentString"),
+            },
+            Example {
+                title: "snake to pascal",
+                source: r#"to_case("http_status_code", "pascal")"#,
+                result: Ok("HttpStatusCode"),
+            },
+            Example {
+                title: "screaming snake",
+                source: r#"to_case("maxRetryCount", "screaming_snake")"#,
+                result: Ok("MAX_RETRY_COUNT"),
+            },
+            Example {
+                title: "already in the target case",
+                source: r#"to_case("http_request", "snake")"#,
+                result: Ok("http_request"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let case =
+            Case::from_value(&arguments.required_enum("target", &Case::all_value(), state)?);
+
+        Ok(ToCaseFn { value, case }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ToCaseFn {
+    value: Box<dyn Expression>,
+    case: Case,
+}
+
+impl FunctionExpression for ToCaseFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        Ok(to_case(&value.try_bytes_utf8_lossy()?, self.case).into())
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::bytes().infallible()
+    }
+}
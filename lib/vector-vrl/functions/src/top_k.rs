@@ -0,0 +1,107 @@
+use std::collections::BTreeMap;
+
+use vrl::prelude::*;
+
+fn top_k(value: Vec<Value>, k: i64) -> Resolved {
+    let k = usize::try_from(k).map_err(|_| "k must be a non-negative integer")?;
+
+    // Kept in order of first appearance so that the stable sort below breaks ties by it.
+    let mut counts: Vec<(Value, i64)> = Vec::new();
+    for item in value {
+        match counts.iter_mut().find(|(value, _)| *value == item) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((item, 1)),
+        }
+    }
+
+    counts.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+    Ok(Value::Array(
+        counts
+            .into_iter()
+            .take(k)
+            .map(|(value, count)| {
+                Value::Object(ObjectMap::from([
+                    ("value".into(), value),
+                    ("count".into(), Value::Integer(count)),
+                ]))
+            })
+            .collect(),
+    ))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct TopK;
+
+impl Function for TopK {
+    fn identifier(&self) -> &'static str {
+        "top_k"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "k",
+                kind: kind::INTEGER,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "most frequent values",
+                source: r#"top_k!(["a", "b", "a", "c", "b", "a"], 2)"#,
+                result: Ok(r#"[{"value": "a", "count": 3}, {"value": "b", "count": 2}]"#),
+            },
+            Example {
+                title: "ties keep first appearance order",
+                source: r#"top_k!([3, 1, 2, 1, 3, 2], 3)"#,
+                result: Ok(
+                    r#"[{"value": 3, "count": 2}, {"value": 1, "count": 2}, {"value": 2, "count": 2}]"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let k = arguments.required("k");
+
+        Ok(TopKFn { value, k }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct TopKFn {
+    value: Box<dyn Expression>,
+    k: Box<dyn Expression>,
+}
+
+impl FunctionExpression for TopKFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_array()?;
+        let k = self.k.resolve(ctx)?.try_integer()?;
+
+        top_k(value, k)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::array(Collection::from_unknown(Kind::object(BTreeMap::from([
+            ("value".into(), Kind::any()),
+            ("count".into(), Kind::integer()),
+        ]))))
+        .fallible()
+    }
+}
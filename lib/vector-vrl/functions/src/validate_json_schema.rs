@@ -0,0 +1,463 @@
+use std::collections::BTreeMap;
+
+use regex::Regex;
+use vrl::diagnostic::Label;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum JsonType {
+    Null,
+    Boolean,
+    Integer,
+    Number,
+    String,
+    Array,
+    Object,
+}
+
+impl JsonType {
+    fn parse(name: &str) -> Option<Self> {
+        Some(match name {
+            "null" => Self::Null,
+            "boolean" => Self::Boolean,
+            "integer" => Self::Integer,
+            "number" => Self::Number,
+            "string" => Self::String,
+            "array" => Self::Array,
+            "object" => Self::Object,
+            _ => return None,
+        })
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Null => "null",
+            Self::Boolean => "boolean",
+            Self::Integer => "integer",
+            Self::Number => "number",
+            Self::String => "string",
+            Self::Array => "array",
+            Self::Object => "object",
+        }
+    }
+
+    /// Returns the JSON type `value` is serialized as. Timestamps and regular expressions are
+    /// serialized as strings.
+    fn of(value: &Value) -> Self {
+        match value {
+            Value::Null => Self::Null,
+            Value::Boolean(_) => Self::Boolean,
+            Value::Integer(_) => Self::Integer,
+            Value::Float(_) => Self::Number,
+            Value::Bytes(_) | Value::Timestamp(_) | Value::Regex(_) => Self::String,
+            Value::Array(_) => Self::Array,
+            Value::Object(_) => Self::Object,
+        }
+    }
+
+    fn matches(self, value: &Value) -> bool {
+        match (self, value) {
+            (Self::Integer, Value::Float(float)) => float.fract() == 0.0,
+            (Self::Number, Value::Integer(_)) => true,
+            (expected, value) => expected == Self::of(value),
+        }
+    }
+}
+
+/// A compiled JSON Schema.
+///
+/// Only the validation keywords that apply to a single instance are supported: `type`, `enum`,
+/// `const`, `required`, `properties`, `additionalProperties`, `items`, `minItems`, `maxItems`,
+/// `minLength`, `maxLength`, `pattern`, `minimum`, `maximum`, `exclusiveMinimum` and
+/// `exclusiveMaximum`. Annotation keywords such as `title` and `description` are accepted and
+/// ignored. Any other keyword is rejected, since silently ignoring a constraint like `oneOf` or
+/// `$ref` would report every value as valid.
+#[derive(Clone, Debug, Default)]
+struct Schema {
+    /// Set for the boolean schema `false`, which rejects every value.
+    reject_all: bool,
+    types: Option<Vec<JsonType>>,
+    enum_values: Option<Vec<Value>>,
+    const_value: Option<Value>,
+    required: Vec<KeyString>,
+    properties: BTreeMap<KeyString, Schema>,
+    additional_properties: Option<Box<Schema>>,
+    items: Option<Box<Schema>>,
+    min_items: Option<usize>,
+    max_items: Option<usize>,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    pattern: Option<Regex>,
+    minimum: Option<f64>,
+    maximum: Option<f64>,
+    exclusive_minimum: Option<f64>,
+    exclusive_maximum: Option<f64>,
+}
+
+impl Schema {
+    fn compile(schema: &Value) -> Result<Self, String> {
+        let object = match schema {
+            Value::Boolean(accept) => {
+                return Ok(Self {
+                    reject_all: !accept,
+                    ..Default::default()
+                })
+            }
+            Value::Object(object) => object,
+            value => return Err(format!("schema must be an object, got {}", value.kind())),
+        };
+
+        let mut compiled = Self::default();
+        for (keyword, value) in object {
+            let invalid = |expected: &str| format!("{:?} must be {expected}", keyword.as_str());
+            let as_count = || count(value).ok_or_else(|| invalid("a non-negative integer"));
+            let as_number = || number(value).ok_or_else(|| invalid("a number"));
+
+            match keyword.as_str() {
+                "type" => {
+                    let names = match value {
+                        Value::Array(names) => names.iter().collect(),
+                        name => vec![name],
+                    };
+                    let types = names
+                        .into_iter()
+                        .map(|name| {
+                            name.as_str()
+                                .and_then(|name| JsonType::parse(&name))
+                                .ok_or_else(|| invalid("a type name or an array of type names"))
+                        })
+                        .collect::<Result<_, _>>()?;
+                    compiled.types = Some(types);
+                }
+                "enum" => {
+                    let values = value.as_array().ok_or_else(|| invalid("an array"))?;
+                    compiled.enum_values = Some(values.to_vec());
+                }
+                "const" => compiled.const_value = Some(value.clone()),
+                "required" => {
+                    compiled.required = value
+                        .as_array()
+                        .ok_or_else(|| invalid("an array of strings"))?
+                        .iter()
+                        .map(|name| {
+                            name.as_str()
+                                .map(|name| KeyString::from(name.into_owned()))
+                                .ok_or_else(|| invalid("an array of strings"))
+                        })
+                        .collect::<Result<_, _>>()?;
+                }
+                "properties" => {
+                    compiled.properties = value
+                        .as_object()
+                        .ok_or_else(|| invalid("an object"))?
+                        .iter()
+                        .map(|(name, schema)| {
+                            Self::compile(schema).map(|schema| (name.clone(), schema))
+                        })
+                        .collect::<Result<_, _>>()?;
+                }
+                "additionalProperties" => {
+                    compiled.additional_properties = Some(Box::new(Self::compile(value)?));
+                }
+                "items" => compiled.items = Some(Box::new(Self::compile(value)?)),
+                "minItems" => compiled.min_items = Some(as_count()?),
+                "maxItems" => compiled.max_items = Some(as_count()?),
+                "minLength" => compiled.min_length = Some(as_count()?),
+                "maxLength" => compiled.max_length = Some(as_count()?),
+                "pattern" => {
+                    let pattern = value.as_str().ok_or_else(|| invalid("a string"))?;
+                    let pattern = Regex::new(&pattern)
+                        .map_err(|err| format!("\"pattern\" is not a valid regex: {err}"))?;
+                    compiled.pattern = Some(pattern);
+                }
+                "minimum" => compiled.minimum = Some(as_number()?),
+                "maximum" => compiled.maximum = Some(as_number()?),
+                "exclusiveMinimum" => compiled.exclusive_minimum = Some(as_number()?),
+                "exclusiveMaximum" => compiled.exclusive_maximum = Some(as_number()?),
+                "$schema" | "$id" | "$comment" | "title" | "description" | "default"
+                | "examples" | "deprecated" | "readOnly" | "writeOnly" => {}
+                keyword => return Err(format!("unsupported keyword {keyword:?}")),
+            }
+        }
+
+        Ok(compiled)
+    }
+
+    fn validate(&self, value: &Value, path: &str, errors: &mut Vec<Value>) {
+        let mut error = |message: String| {
+            let path = if path.is_empty() { "." } else { path };
+            errors.push(format!("{path}: {message}").into());
+        };
+
+        if self.reject_all {
+            error("no value is allowed".to_owned());
+            return;
+        }
+
+        if let Some(types) = &self.types {
+            if !types.iter().any(|expected| expected.matches(value)) {
+                let expected = types
+                    .iter()
+                    .map(|t| t.name())
+                    .collect::<Vec<_>>()
+                    .join(" or ");
+                error(format!(
+                    "expected type {expected}, got {}",
+                    JsonType::of(value).name()
+                ));
+                return;
+            }
+        }
+
+        if let Some(values) = &self.enum_values {
+            if !values.contains(value) {
+                error("value is not one of the allowed values".to_owned());
+            }
+        }
+
+        if let Some(expected) = &self.const_value {
+            if expected != value {
+                error("value does not equal the constant value".to_owned());
+            }
+        }
+
+        match value {
+            Value::Bytes(bytes) => {
+                let string = String::from_utf8_lossy(bytes);
+                let length = string.chars().count();
+
+                if let Some(min) = self.min_length.filter(|min| length < *min) {
+                    error(format!("string is shorter than {min} characters"));
+                }
+                if let Some(max) = self.max_length.filter(|max| length > *max) {
+                    error(format!("string is longer than {max} characters"));
+                }
+                if let Some(pattern) = self.pattern.as_ref().filter(|p| !p.is_match(&string)) {
+                    error(format!(
+                        "string does not match pattern {:?}",
+                        pattern.as_str()
+                    ));
+                }
+            }
+            Value::Integer(_) | Value::Float(_) => {
+                let number = number(value).expect("value is a number");
+
+                if let Some(min) = self.minimum.filter(|min| number < *min) {
+                    error(format!("{number} is less than the minimum of {min}"));
+                }
+                if let Some(max) = self.maximum.filter(|max| number > *max) {
+                    error(format!("{number} is greater than the maximum of {max}"));
+                }
+                if let Some(min) = self.exclusive_minimum.filter(|min| number <= *min) {
+                    error(format!("{number} is not greater than {min}"));
+                }
+                if let Some(max) = self.exclusive_maximum.filter(|max| number >= *max) {
+                    error(format!("{number} is not less than {max}"));
+                }
+            }
+            Value::Array(array) => {
+                if let Some(min) = self.min_items.filter(|min| array.len() < *min) {
+                    error(format!("array has fewer than {min} items"));
+                }
+                if let Some(max) = self.max_items.filter(|max| array.len() > *max) {
+                    error(format!("array has more than {max} items"));
+                }
+                if let Some(items) = &self.items {
+                    for (index, item) in array.iter().enumerate() {
+                        items.validate(item, &format!("{path}[{index}]"), errors);
+                    }
+                }
+            }
+            Value::Object(object) => {
+                for name in &self.required {
+                    if !object.contains_key(name) {
+                        error(format!("missing required property {:?}", name.as_str()));
+                    }
+                }
+                for (name, value) in object {
+                    let path = format!("{path}.{}", name.as_str());
+                    match self.properties.get(name) {
+                        Some(schema) => schema.validate(value, &path, errors),
+                        None => {
+                            if let Some(schema) = &self.additional_properties {
+                                if schema.reject_all {
+                                    errors.push(format!("{path}: property is not allowed").into());
+                                } else {
+                                    schema.validate(value, &path, errors);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Resolves a schema argument, reading the schema from a JSON file when it is given as a path.
+fn load(schema: Value) -> Result<Value, String> {
+    match schema {
+        Value::Bytes(path) => {
+            let path = String::from_utf8_lossy(&path);
+            let contents = std::fs::read_to_string(path.as_ref())
+                .map_err(|err| format!("unable to read schema file {path:?}: {err}"))?;
+            serde_json::from_str::<serde_json::Value>(&contents)
+                .map(Value::from)
+                .map_err(|err| format!("schema file {path:?} is not valid JSON: {err}"))
+        }
+        schema => Ok(schema),
+    }
+}
+
+fn count(value: &Value) -> Option<usize> {
+    value
+        .as_integer()
+        .and_then(|count| usize::try_from(count).ok())
+}
+
+fn number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Integer(integer) => Some(*integer as f64),
+        Value::Float(float) => Some(float.into_inner()),
+        _ => None,
+    }
+}
+
+fn validate_json_schema(value: &Value, schema: &Schema) -> Resolved {
+    let mut errors = Vec::new();
+    schema.validate(value, "", &mut errors);
+
+    Ok(Value::Array(errors))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ValidateJsonSchema;
+
+impl Function for ValidateJsonSchema {
+    fn identifier(&self) -> &'static str {
+        "validate_json_schema"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ANY,
+                required: true,
+            },
+            Parameter {
+                keyword: "schema",
+                kind: kind::OBJECT | kind::BOOLEAN | kind::BYTES,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "valid value",
+                source: r#"validate_json_schema({"name": "alice", "age": 42}, {"type": "object", "required": ["name"], "properties": {"age": {"type": "integer"}}})"#,
+                result: Ok("[]"),
+            },
+            Example {
+                title: "missing required property",
+                source: r#"validate_json_schema({"age": 42}, {"type": "object", "required": ["name"]})"#,
+                result: Ok(r#"[".: missing required property \"name\""]"#),
+            },
+            Example {
+                title: "property of the wrong type",
+                source: r#"validate_json_schema({"name": "alice", "age": "42"}, {"required": ["name"], "properties": {"age": {"type": "integer"}}})"#,
+                result: Ok(r#"[".age: expected type integer, got string"]"#),
+            },
+            Example {
+                title: "nested array items",
+                source: r#"validate_json_schema({"tags": ["a", 1]}, {"properties": {"tags": {"type": "array", "items": {"type": "string"}}}})"#,
+                result: Ok(r#"[".tags[1]: expected type string, got integer"]"#),
+            },
+            Example {
+                title: "annotations are ignored",
+                source: r#"validate_json_schema("x", {"title": "Name", "description": "A name", "type": "string"})"#,
+                result: Ok("[]"),
+            },
+            Example {
+                title: "unsupported keyword",
+                source: r#"validate_json_schema!("x", object!(parse_json!(s'{"oneOf": [{"type": "integer"}]}')))"#,
+                result: Err(
+                    r#"function call error for "validate_json_schema" at (0:85): invalid JSON schema: unsupported keyword "oneOf""#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        state: &TypeState,
+        ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let schema = arguments.required("schema");
+
+        // Literal schemas and schema files are compiled once, so that malformed schemas are
+        // reported when the program is compiled rather than for every event.
+        let schema = match schema.resolve_constant(state) {
+            Some(literal) => match load(literal).and_then(|schema| Schema::compile(&schema)) {
+                Ok(schema) => SchemaArgument::Compiled(Box::new(schema)),
+                Err(message) => {
+                    let error = ExpressionError::Error {
+                        message: format!("invalid JSON schema: {message}"),
+                        labels: vec![Label::primary("invalid JSON schema", ctx.span())],
+                        notes: vec![],
+                    };
+
+                    return Err(Box::new(error) as Box<dyn DiagnosticMessage>);
+                }
+            },
+            None => SchemaArgument::Dynamic(schema),
+        };
+
+        Ok(ValidateJsonSchemaFn { value, schema }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+enum SchemaArgument {
+    Compiled(Box<Schema>),
+    Dynamic(Box<dyn Expression>),
+}
+
+#[derive(Debug, Clone)]
+struct ValidateJsonSchemaFn {
+    value: Box<dyn Expression>,
+    schema: SchemaArgument,
+}
+
+impl FunctionExpression for ValidateJsonSchemaFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        match &self.schema {
+            SchemaArgument::Compiled(schema) => validate_json_schema(&value, schema),
+            SchemaArgument::Dynamic(schema) => {
+                let schema = match schema.resolve(ctx)? {
+                    Value::Bytes(_) => return Err("schema file path must be a literal".into()),
+                    schema => Schema::compile(&schema)
+                        .map_err(|message| format!("invalid JSON schema: {message}"))?,
+                };
+
+                validate_json_schema(&value, &schema)
+            }
+        }
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        let type_def = TypeDef::array(Collection::from_unknown(Kind::bytes()));
+
+        match self.schema {
+            SchemaArgument::Compiled(_) => type_def.infallible(),
+            SchemaArgument::Dynamic(_) => type_def.fallible(),
+        }
+    }
+}
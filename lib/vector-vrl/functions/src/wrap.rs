@@ -0,0 +1,152 @@
+use unicode_segmentation::UnicodeSegmentation;
+use vrl::prelude::*;
+
+/// Wraps a single line, with no embedded newlines, at word boundaries.
+fn wrap_line(line: &str, width: usize, break_long_words: bool, lines: &mut Vec<String>) {
+    let mut current = String::new();
+    let mut current_width = 0;
+
+    for word in line.split_whitespace() {
+        let word_width = word.graphemes(true).count();
+
+        if current_width > 0 && current_width + 1 + word_width <= width {
+            current.push(' ');
+            current.push_str(word);
+            current_width += 1 + word_width;
+            continue;
+        }
+
+        if current_width > 0 {
+            lines.push(std::mem::take(&mut current));
+        }
+
+        if break_long_words && word_width > width {
+            let graphemes = word.graphemes(true).collect::<Vec<_>>();
+            let mut chunks = graphemes.chunks(width).map(<[&str]>::concat).peekable();
+            while let Some(chunk) = chunks.next() {
+                if chunks.peek().is_some() {
+                    lines.push(chunk);
+                } else {
+                    current_width = chunk.graphemes(true).count();
+                    current = chunk;
+                }
+            }
+        } else {
+            current = word.to_owned();
+            current_width = word_width;
+        }
+    }
+
+    lines.push(current);
+}
+
+fn wrap(value: &str, width: i64, break_long_words: bool) -> Resolved {
+    let width = usize::try_from(width)
+        .ok()
+        .filter(|width| *width > 0)
+        .ok_or_else(|| format!("width must be a positive integer, got {width}"))?;
+
+    let mut lines = Vec::new();
+    for line in value.lines() {
+        wrap_line(line, width, break_long_words, &mut lines);
+    }
+
+    Ok(lines.join("\n").into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Wrap;
+
+impl Function for Wrap {
+    fn identifier(&self) -> &'static str {
+        "wrap"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "width",
+                kind: kind::INTEGER,
+                required: true,
+            },
+            Parameter {
+                keyword: "break_long_words",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "wrap at word boundaries",
+                source: r#"wrap!("disk usage on db-01 exceeded ninety percent", 16)"#,
+                result: Ok("disk usage on\ndb-01 exceeded\nninety percent"),
+            },
+            Example {
+                title: "keep existing line breaks",
+                source: r#"wrap!("alert: disk full\nhost: db-01", 12)"#,
+                result: Ok("alert: disk\nfull\nhost: db-01"),
+            },
+            Example {
+                title: "word longer than the width",
+                source: r#"wrap!("id a1b2c3d4e5f6 found", 5)"#,
+                result: Ok("id\na1b2c3d4e5f6\nfound"),
+            },
+            Example {
+                title: "break long words",
+                source: r#"wrap!("id a1b2c3d4e5f6 found", 5, break_long_words: true)"#,
+                result: Ok("id\na1b2c\n3d4e5\nf6\nfound"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let width = arguments.required("width");
+        let break_long_words = arguments.optional("break_long_words");
+
+        Ok(WrapFn {
+            value,
+            width,
+            break_long_words,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct WrapFn {
+    value: Box<dyn Expression>,
+    width: Box<dyn Expression>,
+    break_long_words: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for WrapFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let value = value.try_bytes_utf8_lossy()?;
+        let width = self.width.resolve(ctx)?.try_integer()?;
+        let break_long_words = match &self.break_long_words {
+            Some(break_long_words) => break_long_words.resolve(ctx)?.try_boolean()?,
+            None => false,
+        };
+
+        wrap(&value, width, break_long_words)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::bytes().fallible()
+    }
+}
@@ -0,0 +1,93 @@
+use vrl::prelude::*;
+
+fn zip_object(keys: Vec<Value>, values: Vec<Value>) -> Resolved {
+    keys.into_iter()
+        .zip(values)
+        .enumerate()
+        .map(|(index, (key, value))| match key {
+            Value::Bytes(key) => Ok((String::from_utf8_lossy(&key).into(), value)),
+            key => Err(format!("key {index} must be a string, got {}", key.kind()).into()),
+        })
+        .collect::<Result<ObjectMap, _>>()
+        .map(Value::Object)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ZipObject;
+
+impl Function for ZipObject {
+    fn identifier(&self) -> &'static str {
+        "zip_object"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "keys",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "values",
+                kind: kind::ARRAY,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "equal length arrays",
+                source: r#"zip_object!(["host", "status"], ["web-1", 200])"#,
+                result: Ok(r#"{"host": "web-1", "status": 200}"#),
+            },
+            Example {
+                title: "more keys than values",
+                source: r#"zip_object!(["host", "status", "env"], ["web-1", 200])"#,
+                result: Ok(r#"{"host": "web-1", "status": 200}"#),
+            },
+            Example {
+                title: "more values than keys",
+                source: r#"zip_object!(["host"], ["web-1", 200])"#,
+                result: Ok(r#"{"host": "web-1"}"#),
+            },
+            Example {
+                title: "non-string key",
+                source: r#"zip_object(["host", 1], ["web-1", 200]) ?? "invalid""#,
+                result: Ok("invalid"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let keys = arguments.required("keys");
+        let values = arguments.required("values");
+
+        Ok(ZipObjectFn { keys, values }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ZipObjectFn {
+    keys: Box<dyn Expression>,
+    values: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ZipObjectFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let keys = self.keys.resolve(ctx)?.try_array()?;
+        let values = self.values.resolve(ctx)?.try_array()?;
+
+        zip_object(keys, values)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::object(Collection::any()).fallible()
+    }
+}
@@ -239,6 +239,7 @@ fn encoder_framing_to_decoding_framer(framing: encoding::FramingConfig) -> decod
                 character_delimited: decoding::CharacterDelimitedDecoderOptions {
                     delimiter: config.character_delimited.delimiter,
                     max_length: None,
+                    additional_delimiters: Vec::new(),
                 },
             })
         }
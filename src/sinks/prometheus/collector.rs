@@ -400,7 +400,11 @@ impl MetricCollector for TimeSeries {
         let timeseries = self
             .buffer
             .into_iter()
-            .map(|(labels, samples)| proto::TimeSeries { labels, samples })
+            .map(|(labels, samples)| proto::TimeSeries {
+                labels,
+                samples,
+                histograms: vec![],
+            })
             .collect::<Vec<_>>();
         let metadata = self
             .metadata
@@ -490,6 +494,7 @@ mod tests {
                                 value: $svalue,
                                 timestamp: $timestamp,
                             }],
+                            histograms: vec![],
                         },
                     )*
                 ],
@@ -2515,6 +2515,7 @@ impl ValidatableComponent for DatadogAgentConfig {
                 character_delimited: CharacterDelimitedDecoderOptions {
                     delimiter: b',',
                     max_length: Some(usize::MAX),
+                    additional_delimiters: Vec::new(),
                 },
             }
             .into(),
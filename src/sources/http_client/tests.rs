@@ -152,6 +152,7 @@ async fn json_decoding_character_delimited() {
             character_delimited: CharacterDelimitedDecoderOptions {
                 delimiter: b',',
                 max_length: Some(usize::MAX),
+                additional_delimiters: Vec::new(),
             },
         }),
         headers: HashMap::new(),
@@ -130,6 +130,9 @@ fn reparse_groups(
                     );
                 }
             }
+            // There is no native histogram metric value to convert these into yet, so they are
+            // dropped as before.
+            GroupKind::NativeHistogram(_) => {}
             GroupKind::Summary(metrics) => {
                 for (key, metric) in metrics {
                     let tags = combine_tags(key.labels, tag_overrides.clone());
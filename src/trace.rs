@@ -66,6 +66,7 @@ pub fn init(color: bool, json: bool, levels: &str, internal_log_rate_limit: u64)
 
     let broadcast_layer = RateLimitedLayer::new(BroadcastLayer::new())
         .with_default_limit(internal_log_rate_limit)
+        .with_flush_on_close(true)
         .with_filter(fmt_filter.clone());
 
     let subscriber = tracing_subscriber::registry()
@@ -95,8 +96,9 @@ pub fn init(color: bool, json: bool, levels: &str, internal_log_rate_limit: u64)
         #[cfg(test)]
         let formatter = formatter.with_test_writer();
 
-        let rate_limited =
-            RateLimitedLayer::new(formatter).with_default_limit(internal_log_rate_limit);
+        let rate_limited = RateLimitedLayer::new(formatter)
+            .with_default_limit(internal_log_rate_limit)
+            .with_flush_on_close(true);
         let subscriber = subscriber.with(rate_limited.with_filter(fmt_filter));
 
         _ = subscriber.try_init();
@@ -108,8 +110,9 @@ pub fn init(color: bool, json: bool, levels: &str, internal_log_rate_limit: u64)
         #[cfg(test)]
         let formatter = formatter.with_test_writer();
 
-        let rate_limited =
-            RateLimitedLayer::new(formatter).with_default_limit(internal_log_rate_limit);
+        let rate_limited = RateLimitedLayer::new(formatter)
+            .with_default_limit(internal_log_rate_limit)
+            .with_flush_on_close(true);
         let subscriber = subscriber.with(rate_limited.with_filter(fmt_filter));
 
         _ = subscriber.try_init();
@@ -124,6 +124,22 @@ components: sources: [Name=string]: {
 											unit: "bytes"
 										}
 									}
+									additional_delimiters: {
+										description: """
+											Additional bytes that also delimit frames. When set, any of `delimiter` and these bytes
+											ends a frame. If the newline byte (`10`) is one of the delimiters, a carriage return
+											(`13`) directly before it is stripped, so that frames ending in CRLF and LF can be mixed.
+											"""
+										required: false
+										common:   false
+										type: array: {
+											default: []
+											items: type: uint: {
+												examples: [10, 13]
+												unit: null
+											}
+										}
+									}
 								}
 							}
 							newline_delimited: {
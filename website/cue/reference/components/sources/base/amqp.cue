@@ -306,6 +306,20 @@ base: components: sources: amqp: configuration: {
 				relevant_when: "method = \"character_delimited\""
 				required:      true
 				type: object: options: {
+					additional_delimiters: {
+						description: """
+																Additional bytes that also delimit byte sequences.
+
+																When set, any of `delimiter` and these bytes ends a frame. If the newline byte (`10`) is
+																one of the delimiters, a carriage return (`13`) directly before it is stripped, so that
+																frames ending in CRLF and LF can be mixed.
+																"""
+						required: false
+						type: array: {
+							default: []
+							items: type: uint: examples: [10]
+						}
+					}
 					delimiter: {
 						description: "The character that delimits byte sequences."
 						required:    true
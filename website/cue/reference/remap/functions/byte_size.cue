@@ -0,0 +1,40 @@
+package metadata
+
+remap: functions: byte_size: {
+	category: "Type"
+	description: """
+		Estimates the number of bytes the `value` occupies when serialized as JSON.
+
+		The result is approximate: characters that would be escaped are not accounted for, and floats
+		and timestamps are estimated from their display length. It is intended for capacity planning
+		and for dropping oversized events, not for exact size accounting.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The value to estimate the serialized size of."
+			required:    true
+			type: ["any"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["integer"]
+
+	examples: [
+		{
+			title: "Estimate the size of a string"
+			source: #"""
+				byte_size("hello")
+				"""#
+			return: 7
+		},
+		{
+			title: "Estimate the size of a nested object"
+			source: #"""
+				byte_size({"user": {"name": "alice", "tags": ["admin", "ops"]}, "count": 42})
+				"""#
+			return: 59
+		},
+	]
+}
@@ -0,0 +1,51 @@
+package metadata
+
+remap: functions: coalesce_nullish: {
+	category: "Type"
+	description: """
+		Returns the first element of `values` that is not nullish, or `null` if every element is
+		nullish.
+
+		An element is nullish under the same rules as `is_nullish`, or if it is equal to any of the
+		`extra` values.
+		"""
+
+	arguments: [
+		{
+			name:        "values"
+			description: "The candidate values, in order of preference."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "extra"
+			description: #"Additional values to treat as nullish, for example `"N/A"`."#
+			required:    false
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["any"]
+		rules: [
+			#"Returns `null` if every element of `values` is nullish."#,
+		]
+	}
+
+	examples: [
+		{
+			title: "Pick the first non-nullish value"
+			source: #"""
+				coalesce_nullish([null, "", "-", "value"])
+				"""#
+			return: "value"
+		},
+		{
+			title: "Treat custom values as nullish"
+			source: #"""
+				coalesce_nullish(["N/A", "null", "value"], extra: ["N/A", "null"])
+				"""#
+			return: "value"
+		},
+	]
+}
@@ -0,0 +1,44 @@
+package metadata
+
+remap: functions: concat: {
+	category: "Array"
+	description: """
+		Concatenates the elements of `values` into a single array, in order. Elements that are
+		arrays have their items added one level deep, while any other element is added as a single
+		item.
+		"""
+	notices: [
+		"""
+			VRL functions don't take a variable number of arguments, so `concat` takes a single
+			`values` array holding everything to concatenate, rather than `concat(a, b, c)`.
+			""",
+	]
+
+	arguments: [
+		{
+			name:        "values"
+			description: "The arrays and scalars to concatenate."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Concatenate arrays"
+			source: #"""
+				concat([[1, 2], [3], ["a", "b"]])
+				"""#
+			return: [1, 2, 3, "a", "b"]
+		},
+		{
+			title: "Concatenate arrays and scalars"
+			source: #"""
+				concat([[1, 2], 3, "a", [true]])
+				"""#
+			return: [1, 2, 3, "a", true]
+		},
+	]
+}
@@ -0,0 +1,30 @@
+package metadata
+
+remap: functions: crc32: {
+	category:    "Cryptography"
+	description: """
+		Calculates the CRC-32 checksum of the `value`, as used by zlib, gzip, and ZIP. The checksum
+		is returned as a non-negative integer.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to calculate the checksum for."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["integer"]
+
+	examples: [
+		{
+			title: "Calculate CRC-32"
+			source: #"""
+				crc32("123456789")
+				"""#
+			return: 3421780262
+		},
+	]
+}
@@ -0,0 +1,30 @@
+package metadata
+
+remap: functions: crc32c: {
+	category:    "Cryptography"
+	description: """
+		Calculates the CRC-32C (Castagnoli) checksum of the `value`, as used by iSCSI and Kafka. The
+		checksum is returned as a non-negative integer.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to calculate the checksum for."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["integer"]
+
+	examples: [
+		{
+			title: "Calculate CRC-32C"
+			source: #"""
+				crc32c("123456789")
+				"""#
+			return: 3808858755
+		},
+	]
+}
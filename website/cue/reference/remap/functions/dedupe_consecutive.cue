@@ -0,0 +1,39 @@
+package metadata
+
+remap: functions: dedupe_consecutive: {
+	category: "Array"
+	description: """
+		Removes consecutive duplicate elements from the `value` array, like the Unix `uniq` command.
+
+		Only adjacent duplicates are collapsed: an element that appears again after a different element
+		is kept. Use `dedupe_consecutive_by` to compare elements by a key.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array to remove consecutive duplicates from."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Collapse adjacent duplicates"
+			source: #"""
+				dedupe_consecutive(["a", "a", "b", "b", "b", "c"])
+				"""#
+			return: ["a", "b", "c"]
+		},
+		{
+			title: "Keep non-adjacent duplicates"
+			source: #"""
+				dedupe_consecutive(["a", "b", "a", "a", "b"])
+				"""#
+			return: ["a", "b", "a", "b"]
+		},
+	]
+}
@@ -0,0 +1,35 @@
+package metadata
+
+remap: functions: dedupe_consecutive_by: {
+	category: "Enumerate"
+	description: """
+		Removes consecutive elements of the `value` array whose key, as returned by the closure, is
+		equal to the key of the element before them. The first element of each run is kept.
+
+		The closure receives the index and value of each element, using the same closure syntax and
+		scoping rules as `filter`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array to remove consecutive duplicates from."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Collapse runs of events with the same message"
+			source: #"""
+				dedupe_consecutive_by([{"msg": "a", "n": 1}, {"msg": "a", "n": 2}, {"msg": "b", "n": 3}]) -> |_index, value| {
+				    value.msg
+				}
+				"""#
+			return: [{"msg": "a", "n": 1}, {"msg": "b", "n": 3}]
+		},
+	]
+}
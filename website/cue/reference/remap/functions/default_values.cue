@@ -0,0 +1,65 @@
+package metadata
+
+remap: functions: default_values: {
+	category: "Object"
+	description: """
+		Fills in keys that are missing from the `value` object with the values from the `defaults`
+		object. Keys that are already present in `value` are never overwritten, unless they are `null`
+		and `replace_null` is enabled.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The object to fill in."
+			required:    true
+			type: ["object"]
+		},
+		{
+			name:        "defaults"
+			description: "The object holding the default values."
+			required:    true
+			type: ["object"]
+		},
+		{
+			name:        "recursive"
+			description: "Whether to also fill in keys of nested objects that are present in both `value` and `defaults`."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+		{
+			name:        "replace_null"
+			description: "Whether keys that are present in `value` but set to `null` are replaced by their default."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Fill in missing keys"
+			source: #"""
+				default_values({"level": "warn", "host": null}, {"level": "info", "host": "localhost", "env": "prod"})
+				"""#
+			return: {"level": "warn", "host": null, "env": "prod"}
+		},
+		{
+			title: "Replace keys that are null"
+			source: #"""
+				default_values({"level": "warn", "host": null}, {"level": "info", "host": "localhost"}, replace_null: true)
+				"""#
+			return: {"level": "warn", "host": "localhost"}
+		},
+		{
+			title: "Fill in nested keys"
+			source: #"""
+				default_values({"http": {"method": "GET"}}, {"http": {"method": "POST", "version": "1.1"}}, recursive: true)
+				"""#
+			return: {"http": {"method": "GET", "version": "1.1"}}
+		},
+	]
+}
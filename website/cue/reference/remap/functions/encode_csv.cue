@@ -0,0 +1,50 @@
+package metadata
+
+remap: functions: encode_csv: {
+	category: "Codec"
+	description: """
+		Encodes the `value` array as a single CSV row.
+
+		Fields containing the delimiter, double quotes, or line breaks are quoted as described in
+		[RFC 4180](\(urls.rfc_4180)), with embedded double quotes doubled. Other scalar fields are
+		converted to strings the same way `to_string` does.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The fields of the row to encode."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "delimiter"
+			description: "The field delimiter to use. Must be a single character."
+			required:    false
+			type: ["string"]
+			default: ","
+		},
+	]
+	internal_failure_reasons: [
+		"The delimiter must be a single character.",
+		"A field of `value` is an array or object.",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Encode a CSV row"
+			source: #"""
+				encode_csv!(["foo", 1, true, null])
+				"""#
+			return: "foo,1,true,"
+		},
+		{
+			title: "Encode fields that need quoting"
+			source: #"""
+				encode_csv!(["a,b", "say \"hi\"", "c"])
+				"""#
+			return: #""a,b","say ""hi""",c"#
+		},
+	]
+}
@@ -0,0 +1,75 @@
+package metadata
+
+remap: functions: encode_xml: {
+	category: "Codec"
+	description: """
+		Encodes the `value` object as an XML document, using the same conventions as `parse_xml`.
+
+		The object becomes the `root` element. Keys starting with `attr_prefix` become attributes
+		of their element, the `text_key` key becomes the element's text, and all other keys become
+		child elements. Arrays repeat the element once per item, and `null` values become empty
+		elements. Scalars are converted to strings the same way `to_string` does, and special
+		characters are escaped.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The object to encode."
+			required:    true
+			type: ["object"]
+		},
+		{
+			name:        "root"
+			description: "The name of the root element."
+			required:    false
+			type: ["string"]
+			default: "root"
+		},
+		{
+			name:        "attr_prefix"
+			description: "The prefix marking keys that are encoded as attributes."
+			required:    false
+			type: ["string"]
+			default: "@"
+		},
+		{
+			name:        "text_key"
+			description: "The key whose value is encoded as the text of its element."
+			required:    false
+			type: ["string"]
+			default: "text"
+		},
+	]
+	internal_failure_reasons: [
+		"A key or `root` is not a valid XML name.",
+		"An attribute or text value is an array or object.",
+		"An array directly contains another array.",
+		"`attr_prefix` is empty.",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Encode nested objects"
+			source: #"""
+				encode_xml!({"user": {"name": "alice", "role": "admin"}})
+				"""#
+			return: "<root><user><name>alice</name><role>admin</role></user></root>"
+		},
+		{
+			title: "Encode attributes and text"
+			source: #"""
+				encode_xml!({"@version": 2, "message": {"@level": "warn", "text": "disk < 10%"}}, root: "event")
+				"""#
+			return: #"<event version="2"><message level="warn">disk &lt; 10%</message></event>"#
+		},
+		{
+			title: "Encode arrays"
+			source: #"""
+				encode_xml!({"tag": ["a", "b"]})
+				"""#
+			return: "<root><tag>a</tag><tag>b</tag></root>"
+		},
+	]
+}
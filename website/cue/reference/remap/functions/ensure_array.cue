@@ -0,0 +1,54 @@
+package metadata
+
+remap: functions: ensure_array: {
+	category: "Array"
+	description: """
+		Returns `value` as an array.
+
+		Arrays are returned as is, `null` becomes an empty array, and any other value is wrapped in a
+		single-element array. When `parse_json` is `true`, a string containing a JSON array is parsed
+		into that array instead.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The value to convert to an array."
+			required:    true
+			type: ["any"]
+		},
+		{
+			name:        "parse_json"
+			description: "Whether to parse a string containing a JSON array into that array."
+			required:    false
+			type: ["boolean"]
+			default: false
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Wrap a scalar"
+			source: #"""
+				ensure_array("web")
+				"""#
+			return: ["web"]
+		},
+		{
+			title: "Convert null"
+			source: #"""
+				ensure_array(null)
+				"""#
+			return: []
+		},
+		{
+			title: "Parse a JSON array string"
+			source: #"""
+				ensure_array("[\"web\", 1]", parse_json: true)
+				"""#
+			return: ["web", 1]
+		},
+	]
+}
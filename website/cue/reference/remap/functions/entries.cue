@@ -0,0 +1,33 @@
+package metadata
+
+remap: functions: entries: {
+	category: "Object"
+	description: """
+		Returns the key-value pairs of the object `value` as an array of `[key, value]` arrays,
+		ordered by key.
+
+		This is the inverse of `from_entries`, and combined with it allows `filter` and `map_values`
+		style transformations that need both the key and the value of each field.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The object to get the entries of."
+			required:    true
+			type: ["object"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Get the entries of an object"
+			source: #"""
+				entries({"host": "web-1", "status": 200})
+				"""#
+			return: [["host", "web-1"], ["status", 200]]
+		},
+	]
+}
@@ -0,0 +1,64 @@
+package metadata
+
+remap: functions: escape: {
+	category: "String"
+	description: """
+		Escapes the `value` string for use in the given `context`.
+
+		* `regex`: backslash escapes all regular expression meta characters.
+		* `sql`: doubles single quotes and escapes backslashes and NUL characters with a backslash, for
+		  use inside a single quoted SQL string literal. This is safe whether or not the database
+		  treats backslashes as escape characters, as MySQL does by default. Databases that follow
+		  the SQL standard, such as PostgreSQL with `standard_conforming_strings` on, keep the
+		  escaping backslashes as part of the string.
+		* `shell`: wraps the string in single quotes, so it's passed to a POSIX shell as one literal word.
+		* `json`: escapes the string for use inside a JSON string, without the surrounding double quotes.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to escape."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "context"
+			description: "The context the escaped string is used in."
+			required:    true
+			enum: {
+				regex: "A regular expression."
+				sql:   "A single quoted SQL string literal."
+				shell: "A POSIX shell command line."
+				json:  "A JSON string."
+			}
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Escape for a regular expression"
+			source: #"""
+				escape("1.5+[x]", "regex")
+				"""#
+			return: #"1\.5\+\[x\]"#
+		},
+		{
+			title: "Escape for a SQL string literal"
+			source: #"""
+				escape("O\\'Brien", "sql")
+				"""#
+			return: #"O\\''Brien"#
+		},
+		{
+			title: "Escape for a shell command"
+			source: #"""
+				escape("it's $(rm -rf /)", "shell")
+				"""#
+			return: #"'it'\''s $(rm -rf /)'"#
+		},
+	]
+}
@@ -0,0 +1,37 @@
+package metadata
+
+remap: functions: first: {
+	category: "Array"
+	description: """
+		Returns the first element of an array, or the first character of a string. Returns `null` if
+		`value` is empty.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array or string."
+			required:    true
+			type: ["array", "string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["any"]
+
+	examples: [
+		{
+			title: "Get the first element of an array"
+			source: #"""
+				first(["a", "b", "c"])
+				"""#
+			return: "a"
+		},
+		{
+			title: "Empty array"
+			source: #"""
+				first([])
+				"""#
+			return: null
+		},
+	]
+}
@@ -0,0 +1,47 @@
+package metadata
+
+remap: functions: format_timestamp_relative: {
+	category: "Timestamp"
+	description: """
+		Formats the `value` as a human readable offset relative to `base`, such as `5 minutes ago`
+		or `in 2 hours`.
+
+		Offsets of less than ten seconds in either direction are rendered as `just now`. Larger
+		offsets are expressed in the largest whole unit that fits, where a month is 30 days and a
+		year is 365 days.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The timestamp to format."
+			required:    true
+			type: ["timestamp"]
+		},
+		{
+			name:        "base"
+			description: "The timestamp the offset is computed against. Defaults to the current time."
+			required:    false
+			type: ["timestamp"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Format a past timestamp"
+			source: #"""
+				format_timestamp_relative(t'2021-02-10T23:27:00Z', base: t'2021-02-10T23:32:00Z')
+				"""#
+			return: "5 minutes ago"
+		},
+		{
+			title: "Format a future timestamp"
+			source: #"""
+				format_timestamp_relative(t'2021-02-11T01:32:00Z', base: t'2021-02-10T23:32:00Z')
+				"""#
+			return: "in 2 hours"
+		},
+	]
+}
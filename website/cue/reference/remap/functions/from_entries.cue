@@ -0,0 +1,55 @@
+package metadata
+
+remap: functions: from_entries: {
+	category: "Object"
+	description: """
+		Builds an object from an array of `[key, value]` arrays, such as the one returned by
+		`entries`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array of `[key, value]` pairs. Each key must be a string."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "duplicates"
+			description: "How to handle a key that appears in more than one pair."
+			required:    false
+			type: ["string"]
+			enum: {
+				error: "Return an error."
+				first: "Keep the value of the first pair with the key."
+				last:  "Keep the value of the last pair with the key."
+			}
+			default: "last"
+		},
+	]
+	internal_failure_reasons: [
+		"An element of `value` is not a two-element array with a string key.",
+		"A key appears more than once and `duplicates` is `error`.",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Build an object from entries"
+			source: #"""
+				from_entries!([["host", "web-1"], ["status", 200]])
+				"""#
+			return: {
+				host:   "web-1"
+				status: 200
+			}
+		},
+		{
+			title: "Keep the first duplicate key"
+			source: #"""
+				from_entries!([["env", "dev"], ["env", "prod"]], duplicates: "first")
+				"""#
+			return: env: "dev"
+		},
+	]
+}
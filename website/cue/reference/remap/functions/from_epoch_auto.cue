@@ -0,0 +1,75 @@
+package metadata
+
+remap: functions: from_epoch_auto: {
+	category: "Convert"
+	description: """
+		Converts the `value` integer from a Unix epoch to a VRL `timestamp`, detecting whether it is
+		expressed in seconds, milliseconds, microseconds, or nanoseconds from its magnitude.
+
+		The detection uses the following thresholds on the absolute value:
+
+		* less than 10^11: seconds
+		* less than 10^14: milliseconds
+		* less than 10^17: microseconds
+		* otherwise: nanoseconds
+
+		These thresholds interpret every epoch between the years 1973 and 5000 correctly. Use the
+		`unit` argument to skip detection for values outside of that range.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The Unix epoch value to convert."
+			required:    true
+			type: ["integer"]
+		},
+		{
+			name:        "unit"
+			description: "The unit of the epoch value. If omitted, the unit is detected from the magnitude of the value."
+			required:    false
+			type: ["string"]
+			enum: {
+				seconds:      "Express Unix time in seconds"
+				milliseconds: "Express Unix time in milliseconds"
+				microseconds: "Express Unix time in microseconds"
+				nanoseconds:  "Express Unix time in nanoseconds"
+			}
+		},
+	]
+	internal_failure_reasons: [
+		"`value` is out of range for a timestamp.",
+	]
+	return: types: ["timestamp"]
+
+	examples: [
+		{
+			title: "Convert an epoch in seconds"
+			source: #"""
+				from_epoch_auto!(1609459200)
+				"""#
+			return: "2021-01-01T00:00:00Z"
+		},
+		{
+			title: "Convert an epoch in milliseconds"
+			source: #"""
+				from_epoch_auto!(1609459200123)
+				"""#
+			return: "2021-01-01T00:00:00.123Z"
+		},
+		{
+			title: "Convert an epoch in nanoseconds"
+			source: #"""
+				from_epoch_auto!(1609459200123456789)
+				"""#
+			return: "2021-01-01T00:00:00.123456789Z"
+		},
+		{
+			title: "Convert an epoch with an explicit unit"
+			source: #"""
+				from_epoch_auto!(5000, unit: "milliseconds")
+				"""#
+			return: "1970-01-01T00:00:05Z"
+		},
+	]
+}
@@ -0,0 +1,68 @@
+package metadata
+
+remap: functions: get_or: {
+	category: "Path"
+	description: """
+		Returns the value at `path` in `value`, or `default` if the path doesn't exist.
+
+		Unlike `get`, which returns `null` for a missing path, this distinguishes a missing path from
+		one that is present and set to `null`. A present `null` is returned as is, unless
+		`null_as_missing` is `true`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The object or array to query."
+			required:    true
+			type: ["object", "array"]
+		},
+		{
+			name:        "path"
+			description: "An array of path segments to look up the value for."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "default"
+			description: "The value to return if the path doesn't exist."
+			required:    true
+			type: ["any"]
+		},
+		{
+			name:        "null_as_missing"
+			description: "Whether to also return `default` when the path is present and set to `null`."
+			required:    false
+			type: ["boolean"]
+			default: false
+		},
+	]
+	internal_failure_reasons: [
+		#"The `path` segment must be a string or an integer."#,
+	]
+	return: types: ["any"]
+
+	examples: [
+		{
+			title: "Absent field"
+			source: #"""
+				get_or!({"http": {}}, ["http", "status"], 200)
+				"""#
+			return: 200
+		},
+		{
+			title: "Present null field"
+			source: #"""
+				get_or!({"http": {"status": null}}, ["http", "status"], 200)
+				"""#
+			return: null
+		},
+		{
+			title: "Present null field treated as missing"
+			source: #"""
+				get_or!({"http": {"status": null}}, ["http", "status"], 200, null_as_missing: true)
+				"""#
+			return: 200
+		},
+	]
+}
@@ -0,0 +1,61 @@
+package metadata
+
+remap: functions: ip_network_info: {
+	category: "IP"
+	description: """
+		Returns information about the network described by the `cidr`: its network and broadcast
+		addresses, the first and last host addresses, the number of addresses, and the prefix length.
+		"""
+	notices: [
+		"""
+			Works with both IPv4 and IPv6 networks. IPv4 `/31` and `/32` networks have no reserved
+			network or broadcast address, so their first and last hosts span the whole network. IPv6
+			networks have no broadcast address, so `broadcast` is always `null`, and `num_addresses`
+			is `null` when the count is too large to be represented as an integer.
+			""",
+	]
+
+	arguments: [
+		{
+			name:        "cidr"
+			description: "The network in CIDR notation, for example `192.168.0.0/24`."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`cidr` is not a valid CIDR.",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "IPv4 network information"
+			source: #"""
+				ip_network_info!("192.168.10.32/24")
+				"""#
+			return: {
+				network:       "192.168.10.0"
+				broadcast:     "192.168.10.255"
+				first_host:    "192.168.10.1"
+				last_host:     "192.168.10.254"
+				num_addresses: 256
+				prefix:        24
+			}
+		},
+		{
+			title: "IPv4 network information for a /30"
+			source: #"""
+				ip_network_info!("10.0.0.6/30")
+				"""#
+			return: {
+				network:       "10.0.0.4"
+				broadcast:     "10.0.0.7"
+				first_host:    "10.0.0.5"
+				last_host:     "10.0.0.6"
+				num_addresses: 4
+				prefix:        30
+			}
+		},
+	]
+}
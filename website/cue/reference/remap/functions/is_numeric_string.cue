@@ -0,0 +1,65 @@
+package metadata
+
+remap: functions: is_numeric_string: {
+	category: "Type"
+	description: """
+		Check if the `value`, with surrounding whitespace trimmed, parses as a number without
+		coercing it. Floats, including scientific notation, are only accepted when `allow_float` is
+		`true`. `inf` and `NaN` are never considered numeric.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to check."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "allow_float"
+			description: "Whether floats are considered numeric, in addition to integers."
+			required:    false
+			type: ["boolean"]
+			default: true
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["boolean"]
+		rules: [
+			#"Returns `true` if `value` is a valid integer, or a valid float when `allow_float` is `true`."#,
+			#"Returns `false` otherwise."#,
+		]
+	}
+
+	examples: [
+		{
+			title: "Valid integer"
+			source: #"""
+				is_numeric_string(" 8080 ")
+				"""#
+			return: true
+		},
+		{
+			title: "Scientific notation"
+			source: #"""
+				is_numeric_string("1.5e3")
+				"""#
+			return: true
+		},
+		{
+			title: "Float not allowed"
+			source: #"""
+				is_numeric_string("3.14", allow_float: false)
+				"""#
+			return: false
+		},
+		{
+			title: "Invalid number"
+			source: #"""
+				is_numeric_string("12abc")
+				"""#
+			return: false
+		},
+	]
+}
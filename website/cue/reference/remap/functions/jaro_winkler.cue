@@ -0,0 +1,54 @@
+package metadata
+
+remap: functions: jaro_winkler: {
+	category: "String"
+	description: """
+		Calculates the [Jaro-Winkler similarity](https://en.wikipedia.org/wiki/Jaro%E2%80%93Winkler_distance)
+		between `a` and `b`, from `0.0` (nothing in common) to `1.0` (identical). Strings are
+		compared by Unicode scalar value, and a common prefix of up to four characters raises the
+		similarity by `prefix_scale` per character.
+		"""
+
+	arguments: [
+		{
+			name:        "a"
+			description: "The first string."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "b"
+			description: "The second string."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "prefix_scale"
+			description: "How much each character of common prefix raises the similarity."
+			required:    false
+			type: ["float"]
+			default: 0.1
+		},
+	]
+	internal_failure_reasons: [
+		"`prefix_scale` is not between `0` and `0.25`.",
+	]
+	return: types: ["float"]
+
+	examples: [
+		{
+			title: "Compare names"
+			source: #"""
+				round(jaro_winkler("MARTHA", "MARHTA"), 4)
+				"""#
+			return: 0.9611
+		},
+		{
+			title: "Without prefix scaling"
+			source: #"""
+				round(jaro_winkler!("MARTHA", "MARHTA", prefix_scale: 0.0), 4)
+				"""#
+			return: 0.9444
+		},
+	]
+}
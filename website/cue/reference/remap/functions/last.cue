@@ -0,0 +1,37 @@
+package metadata
+
+remap: functions: last: {
+	category: "Array"
+	description: """
+		Returns the last element of an array, or the last character of a string. Returns `null` if
+		`value` is empty.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array or string."
+			required:    true
+			type: ["array", "string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["any"]
+
+	examples: [
+		{
+			title: "Get the last element of an array"
+			source: #"""
+				last(["a", "b", "c"])
+				"""#
+			return: "c"
+		},
+		{
+			title: "Empty array"
+			source: #"""
+				last([])
+				"""#
+			return: null
+		},
+	]
+}
@@ -0,0 +1,60 @@
+package metadata
+
+remap: functions: map_entries: {
+	category: "Object"
+	description: """
+		Transforms the keys and values of the `value` object in a single pass. The closure is
+		called with each key and value, and must return a `[key, value]` array that replaces the
+		entry in the returned object.
+
+		Unlike `map_keys` and `map_values`, the transformation is not recursive.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The object to transform."
+			required:    true
+			type: ["object"]
+		},
+		{
+			name:        "duplicates"
+			description: "How to handle two entries that are mapped to the same key."
+			required:    false
+			type: ["string"]
+			enum: {
+				error: "Return an error."
+				first: "Keep the value of the first entry mapped to the key."
+				last:  "Keep the value of the last entry mapped to the key."
+			}
+			default: "last"
+		},
+	]
+	internal_failure_reasons: [
+		"The closure does not return a two-element array with a string key.",
+		"Two entries are mapped to the same key and `duplicates` is `error`.",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Lowercase keys and trim values"
+			source: #"""
+				map_entries!({"Host": " web-1 ", "Env": "prod "}) -> |key, value| {
+					[downcase(key), strip_whitespace(value)]
+				}
+				"""#
+			return: {
+				env:  "prod"
+				host: "web-1"
+			}
+		},
+		{
+			title: "Keep the first duplicate key"
+			source: #"""
+				map_entries!({"a": 1, "b": 2}, duplicates: "first") -> |_key, value| { ["n", value] }
+				"""#
+			return: n: 1
+		},
+	]
+}
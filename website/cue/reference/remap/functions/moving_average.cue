@@ -0,0 +1,56 @@
+package metadata
+
+remap: functions: moving_average: {
+	category: "Array"
+	description: """
+		Returns the trailing moving average of the numbers in `value`: each element of the result
+		is the average of the element at the same position and the `window - 1` elements before it.
+
+		Positions before the window fills are `null`, unless `partial` is `true`, in which case they
+		are the average of the elements seen so far.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array of integers and floats to average."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "window"
+			description: "The number of elements in each average."
+			required:    true
+			type: ["integer"]
+		},
+		{
+			name:        "partial"
+			description: "Whether to average the available elements until the window fills, instead of returning `null`."
+			required:    false
+			type: ["boolean"]
+			default: false
+		},
+	]
+	internal_failure_reasons: [
+		"`window` is not a positive integer.",
+		"`value` contains an element that is not an integer or a float.",
+	]
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Smooth a series"
+			source: #"""
+				moving_average!([2, 4, 6, 8, 10], 3)
+				"""#
+			return: [null, null, 4.0, 6.0, 8.0]
+		},
+		{
+			title: "Average partial windows"
+			source: #"""
+				moving_average!([2, 4, 6, 8, 10], 3, partial: true)
+				"""#
+			return: [2.0, 3.0, 4.0, 6.0, 8.0]
+		},
+	]
+}
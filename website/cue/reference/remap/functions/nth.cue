@@ -0,0 +1,51 @@
+package metadata
+
+remap: functions: nth: {
+	category: "Array"
+	description: """
+		Returns the element of an array, or the character of a string, at index `n`. Negative
+		indices count from the end, so `-1` is the last element. Returns `null` if `n` is out of
+		range, so the result can be checked in an `if` condition without handling an error.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array or string."
+			required:    true
+			type: ["array", "string"]
+		},
+		{
+			name:        "n"
+			description: "The zero-based index of the element."
+			required:    true
+			type: ["integer"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["any"]
+
+	examples: [
+		{
+			title: "Get an element by index"
+			source: #"""
+				nth(["a", "b", "c"], 1)
+				"""#
+			return: "b"
+		},
+		{
+			title: "Count from the end"
+			source: #"""
+				nth(["a", "b", "c"], -3)
+				"""#
+			return: "a"
+		},
+		{
+			title: "Get a character by index"
+			source: #"""
+				nth("héllo", 1)
+				"""#
+			return: "é"
+		},
+	]
+}
@@ -0,0 +1,47 @@
+package metadata
+
+remap: functions: omit: {
+	category: "Object"
+	description: """
+		Returns a copy of `value` with the `keys` removed.
+
+		Nested fields can be removed with a `.` separated path, such as `http.method`. Keys that
+		don't exist in `value` are ignored.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The object to remove keys from."
+			required:    true
+			type: ["object"]
+		},
+		{
+			name:        "keys"
+			description: "The keys, or `.` separated paths, to remove."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: [
+		"An element of `keys` is not a string.",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Remove top-level keys"
+			source: #"""
+				omit!({"host": "web-1", "message": "hi", "level": "info"}, ["host", "level"])
+				"""#
+			return: {"message": "hi"}
+		},
+		{
+			title: "Remove a nested path"
+			source: #"""
+				omit!({"http": {"status": 200, "method": "GET"}, "message": "hi"}, ["http.method"])
+				"""#
+			return: {"http": {"status": 200}, "message": "hi"}
+		},
+	]
+}
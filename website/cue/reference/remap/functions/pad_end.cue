@@ -0,0 +1,63 @@
+package metadata
+
+remap: functions: pad_end: {
+	category: "String"
+	description: """
+		Pads the `value` at the end with `fill` until it is `width` graphemes wide.
+
+		If the `value` is already at least `width` graphemes wide, it is returned unchanged. A
+		multi-character `fill` is repeated and cut off to fit the remaining width exactly.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to pad."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "width"
+			description: "The minimum width of the result, in graphemes."
+			required:    true
+			type: ["integer"]
+		},
+		{
+			name:        "fill"
+			description: "The string to pad with."
+			required:    false
+			default:     " "
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`width` is negative or larger than 1048576.",
+		"`fill` is an empty string.",
+		"The padded string would be larger than 1 MiB.",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Pad a string"
+			source: #"""
+				pad_end!("ab", 7, fill: "-=")
+				"""#
+			return: "ab-=-=-"
+		},
+		{
+			title: "Pad a string that is already wide enough"
+			source: #"""
+				pad_end!("hello", 3)
+				"""#
+			return: "hello"
+		},
+		{
+			title: "Pad a string with multibyte characters"
+			source: #"""
+				pad_end!("héllo", 6, fill: ".")
+				"""#
+			return: "héllo."
+		},
+	]
+}
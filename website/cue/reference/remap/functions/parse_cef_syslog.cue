@@ -0,0 +1,86 @@
+package metadata
+
+remap: functions: parse_cef_syslog: {
+	category: "Parse"
+	description: """
+		Parses the `value` as a CEF (Common Event Format) message wrapped in a [Syslog](\(urls.syslog))
+		envelope. The CEF header and extension fields are returned under the `cef` key and the
+		Syslog fields, the same as [`parse_syslog`](#parse_syslog) returns, under the `syslog` key,
+		so CEF extensions never collide with the Syslog fields.
+		"""
+	notices: [
+		"""
+			Both [RFC 3164](\(urls.syslog_3164)) and [RFC 5424](\(urls.syslog_5424)) headers are
+			supported. If the input has no Syslog header, or the header can't be parsed, `syslog` is
+			`null`. If the input is a Syslog message without a CEF payload, `cef` is `null`.
+			All CEF values are returned as strings.
+			""",
+	]
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to parse."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` is neither a properly formatted CEF message nor a Syslog message.",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Parse a firewall event in an RFC 5424 envelope"
+			source: #"""
+				parse_cef_syslog!(s'<134>1 2024-02-12T10:15:00Z fw01 asa 4242 FW-DENY - CEF:0|Acme|Firewall|9.1|100|Connection denied|7|src=10.0.0.5 dst=192.168.1.10 dpt=443 act=deny')
+				"""#
+			return: {
+				cef: {
+					cefVersion:         "0"
+					deviceVendor:       "Acme"
+					deviceProduct:      "Firewall"
+					deviceVersion:      "9.1"
+					deviceEventClassId: "100"
+					name:               "Connection denied"
+					severity:           "7"
+					src:                "10.0.0.5"
+					dst:                "192.168.1.10"
+					dpt:                "443"
+					act:                "deny"
+				}
+				syslog: {
+					facility:  "local0"
+					severity:  "info"
+					timestamp: "2024-02-12T10:15:00Z"
+					hostname:  "fw01"
+					appname:   "asa"
+					procid:    4242
+					msgid:     "FW-DENY"
+					version:   1
+					message:   "CEF:0|Acme|Firewall|9.1|100|Connection denied|7|src=10.0.0.5 dst=192.168.1.10 dpt=443 act=deny"
+				}
+			}
+		},
+		{
+			title: "Parse bare CEF"
+			source: #"""
+				parse_cef_syslog!("CEF:0|Acme|Firewall|9.1|200|Port scan|9|src=10.0.0.7")
+				"""#
+			return: {
+				cef: {
+					cefVersion:         "0"
+					deviceVendor:       "Acme"
+					deviceProduct:      "Firewall"
+					deviceVersion:      "9.1"
+					deviceEventClassId: "200"
+					name:               "Port scan"
+					severity:           "9"
+					src:                "10.0.0.7"
+				}
+				syslog: null
+			}
+		},
+	]
+}
@@ -0,0 +1,56 @@
+package metadata
+
+remap: functions: parse_duration_parts: {
+	category: "Parse"
+	description: """
+		Parses the `value` into its days, hours, minutes, seconds, and milliseconds components.
+
+		Both compact durations such as `1h30m` and ISO 8601 durations such as `P1DT2H30M` are
+		supported. Compact durations accept the units `ns`, `us`, `ms`, `s`, `m`, `h`, `d`, and `w`.
+		Components that overflow, such as `90m`, are carried into the next larger unit, and
+		sub-millisecond precision is rounded to the nearest millisecond.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string of the duration."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` is not a properly formatted duration.",
+		"`value` is an ISO 8601 duration with a year or month component.",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Parse a compact duration"
+			source: #"""
+				parse_duration_parts!("1d 25h 90m 61.5s")
+				"""#
+			return: {
+				days:         2
+				hours:        2
+				minutes:      31
+				seconds:      1
+				milliseconds: 500
+			}
+		},
+		{
+			title: "Parse an ISO 8601 duration"
+			source: #"""
+				parse_duration_parts!("P1DT2H3M4.005S")
+				"""#
+			return: {
+				days:         1
+				hours:        2
+				minutes:      3
+				seconds:      4
+				milliseconds: 5
+			}
+		},
+	]
+}
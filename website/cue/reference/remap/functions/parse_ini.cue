@@ -0,0 +1,58 @@
+package metadata
+
+remap: functions: parse_ini: {
+	category: "Parse"
+	description: """
+		Parses the `value` as an INI document.
+
+		Each `[section]` becomes a nested object holding the `key = value` entries that follow it.
+		Entries before the first section are returned as top-level keys. Lines starting with `;` or
+		`#` are treated as comments. All values are returned as strings.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string representation of the INI document to parse."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "lowercase_keys"
+			description: "Whether to lowercase section names and keys."
+			required:    false
+			type: ["boolean"]
+			default: false
+		},
+	]
+	internal_failure_reasons: [
+		"`value` contains a line that is neither a section header, a `key = value` entry, nor a comment.",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Parse a sectioned INI document"
+			source: #"""
+				parse_ini!("[server]\nhost = example.com\nport = 8080\n\n[client]\nretries = 3")
+				"""#
+			return: {
+				client: retries: "3"
+				server: {
+					host: "example.com"
+					port: "8080"
+				}
+			}
+		},
+		{
+			title: "Parse an INI document without sections"
+			source: #"""
+				parse_ini!("; generated\nname = app\nversion = 1.2")
+				"""#
+			return: {
+				name:    "app"
+				version: "1.2"
+			}
+		},
+	]
+}
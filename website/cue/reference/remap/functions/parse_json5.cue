@@ -0,0 +1,50 @@
+package metadata
+
+remap: functions: parse_json5: {
+	category: "Parse"
+	description: """
+		Parses the `value` as [JSON5](\(urls.json5)), a relaxed superset of JSON.
+
+		In addition to standard JSON, comments, trailing commas, unquoted object keys, single quoted
+		strings, and the other JSON5 extensions are accepted. The result is made of standard VRL
+		values.
+
+		The non-finite numbers `NaN`, `Infinity`, and `-Infinity` are rejected with an error rather
+		than converted, and arrays and objects may be nested at most 128 levels deep.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string representation of the JSON5 to parse."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` is not a valid JSON5-formatted payload.",
+		"`value` contains a non-finite number (`NaN`, `Infinity`, or `-Infinity`).",
+		"`value` is nested more than 128 levels deep.",
+	]
+	return: types: ["boolean", "integer", "float", "string", "object", "array", "null"]
+
+	examples: [
+		{
+			title: "Parse JSON5 with comments and trailing commas"
+			source: #"""
+				parse_json5!("{ // the hosts\n\"hosts\": [\"a\", \"b\",], }")
+				"""#
+			return: hosts: ["a", "b"]
+		},
+		{
+			title: "Parse JSON5 with unquoted keys"
+			source: #"""
+				parse_json5!("{name: 'app', enabled: true}")
+				"""#
+			return: {
+				name:    "app"
+				enabled: true
+			}
+		},
+	]
+}
@@ -0,0 +1,49 @@
+package metadata
+
+remap: functions: partition: {
+	category: "Enumerate"
+	description: """
+		Split an array into two arrays in a single pass: the elements for which the closure
+		returns `true`, followed by the elements for which it returns `false`. Both arrays keep the
+		original order of the elements.
+
+		The function uses the function closure syntax to allow reading
+		the index-value combination for each item in the array.
+
+		The same scoping rules apply to closure blocks as they do for
+		regular blocks. This means that any variable defined in parent scopes
+		is accessible, and mutations to those variables are preserved,
+		but any new variables instantiated in the closure block are
+		unavailable outside of the block.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array to partition."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["array"]
+		rules: [
+			"Returns a two-element array of `[matching, non_matching]`.",
+		]
+	}
+	examples: [
+		{
+			title: "Partition status codes"
+			input: log: {
+				statuses: [200, 503, 201, 404]
+			}
+			source: #"""
+				partition(array!(.statuses)) -> |_index, value| {
+				    value >= 400
+				}
+				"""#
+			return: [[503, 404], [200, 201]]
+		},
+	]
+}
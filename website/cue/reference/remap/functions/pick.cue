@@ -0,0 +1,47 @@
+package metadata
+
+remap: functions: pick: {
+	category: "Object"
+	description: """
+		Returns a new object containing only the `keys` of `value`.
+
+		Nested fields can be selected with a `.` separated path, such as `http.status`. Keys that
+		don't exist in `value` are ignored.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The object to select keys from."
+			required:    true
+			type: ["object"]
+		},
+		{
+			name:        "keys"
+			description: "The keys, or `.` separated paths, to keep."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: [
+		"An element of `keys` is not a string.",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Keep top-level keys"
+			source: #"""
+				pick!({"host": "web-1", "message": "hi", "level": "info"}, ["host", "level"])
+				"""#
+			return: {"host": "web-1", "level": "info"}
+		},
+		{
+			title: "Keep a nested path"
+			source: #"""
+				pick!({"http": {"status": 200, "method": "GET"}, "message": "hi"}, ["http.status"])
+				"""#
+			return: {"http": {"status": 200}}
+		},
+	]
+}
@@ -0,0 +1,54 @@
+package metadata
+
+remap: functions: rename_keys: {
+	category: "Object"
+	description: """
+		Renames the keys of an object according to a `mapping` of old key names to new key names.
+
+		Keys that are not present in the `mapping` are left intact.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The object whose keys should be renamed."
+			required:    true
+			type: ["object"]
+		},
+		{
+			name:        "mapping"
+			description: "An object mapping existing key names to their new names."
+			required:    true
+			type: ["object"]
+		},
+		{
+			name:        "recursive"
+			description: "Whether to also rename keys of nested objects, including objects inside arrays."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"A renamed key collides with another key in the same object.",
+		"A value in `mapping` is not a string.",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Rename keys"
+			source: #"""
+				rename_keys!({"msg": "hello", "lvl": "info", "host": "a"}, {"msg": "message", "lvl": "level"})
+				"""#
+			return: {"host": "a", "level": "info", "message": "hello"}
+		},
+		{
+			title: "Rename keys recursively"
+			source: #"""
+				rename_keys!({"msg": "hello", "nested": {"msg": "world"}}, {"msg": "message"}, recursive: true)
+				"""#
+			return: {"message": "hello", "nested": {"message": "world"}}
+		},
+	]
+}
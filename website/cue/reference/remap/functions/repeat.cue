@@ -0,0 +1,48 @@
+package metadata
+
+remap: functions: repeat: {
+	category: "String"
+	description: """
+		Repeats the `value` `count` times.
+
+		The result is limited to 1 MiB. The limit is measured in bytes rather than graphemes, as a
+		single grapheme can be arbitrarily large.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to repeat."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "count"
+			description: "The number of times to repeat the string."
+			required:    true
+			type: ["integer"]
+		},
+	]
+	internal_failure_reasons: [
+		"`count` is negative.",
+		"The repeated string would be larger than 1 MiB.",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Repeat a string"
+			source: #"""
+				repeat!("ab", 3)
+				"""#
+			return: "ababab"
+		},
+		{
+			title: "Repeat a multibyte string"
+			source: #"""
+				repeat!("é👍🏽", 2)
+				"""#
+			return: "é👍🏽é👍🏽"
+		},
+	]
+}
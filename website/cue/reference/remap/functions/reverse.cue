@@ -0,0 +1,29 @@
+package metadata
+
+remap: functions: reverse: {
+	category: "Array"
+	description: """
+		Returns the elements of the `value` array in reverse order.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array to reverse."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Reverse an array"
+			source: #"""
+				reverse([1, "two", true])
+				"""#
+			return: [true, "two", 1]
+		},
+	]
+}
@@ -0,0 +1,53 @@
+package metadata
+
+remap: functions: rotate: {
+	category: "Array"
+	description: """
+		Rotates the elements of the `value` array by `n` positions, keeping their relative order.
+
+		A positive `n` rotates right, moving elements from the end of the array to the front. A
+		negative `n` rotates left. Rotations wrap around the length of the array, so rotating by
+		the length of the array returns it unchanged.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array to rotate."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "n"
+			description: "The number of positions to rotate by. Negative values rotate left."
+			required:    true
+			type: ["integer"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Rotate right"
+			source: #"""
+				rotate([1, 2, 3, 4], 1)
+				"""#
+			return: [4, 1, 2, 3]
+		},
+		{
+			title: "Rotate left"
+			source: #"""
+				rotate([1, 2, 3, 4], -1)
+				"""#
+			return: [2, 3, 4, 1]
+		},
+		{
+			title: "Rotate past the length of the array"
+			source: #"""
+				rotate([1, 2, 3, 4], -5)
+				"""#
+			return: [2, 3, 4, 1]
+		},
+	]
+}
@@ -0,0 +1,65 @@
+package metadata
+
+remap: functions: sample_n: {
+	category: "Array"
+	description: """
+		Returns up to `n` randomly selected elements of `value`, in random order. If `n` is larger
+		than the array, a shuffled copy of the whole array is returned.
+		"""
+	notices: [
+		"""
+			With a `seed`, the same input always returns the same sample, on every platform and in
+			every Vector version. Seeded samples use the ChaCha8 generator and a selection algorithm
+			that are part of this function's contract and don't change with dependency upgrades.
+			""",
+	]
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array to sample."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "n"
+			description: "The maximum number of elements to return."
+			required:    true
+			type: ["integer"]
+		},
+		{
+			name:        "seed"
+			description: "A seed that makes the selection deterministic."
+			required:    false
+			type: ["integer"]
+		},
+	]
+	internal_failure_reasons: [
+		"`n` is negative.",
+	]
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Sample three elements"
+			source: #"""
+				length(sample_n!([1, 2, 3, 4, 5, 6, 7, 8], 3))
+				"""#
+			return: 3
+		},
+		{
+			title: "Deterministic sample with a seed"
+			source: #"""
+				sample_n!([1, 2, 3, 4, 5, 6, 7, 8], 3, seed: 7) == sample_n!([1, 2, 3, 4, 5, 6, 7, 8], 3, seed: 7)
+				"""#
+			return: true
+		},
+		{
+			title: "Seeded sample"
+			source: #"""
+				sample_n!([1, 2, 3, 4, 5, 6, 7, 8], 3, seed: 7)
+				"""#
+			return: [4, 2, 5]
+		},
+	]
+}
@@ -0,0 +1,38 @@
+package metadata
+
+remap: functions: set_intersection: {
+	category: "Array"
+	description: """
+		Returns the elements of `a` that are also in `b`.
+
+		Elements are compared by value, duplicates are removed, and the result keeps the elements in
+		the order they first appear.
+		"""
+
+	arguments: [
+		{
+			name:        "a"
+			description: "The first array."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "b"
+			description: "The second array."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Intersect tag sets"
+			source: #"""
+				set_intersection(["web", "prod", "eu"], ["eu", "prod", "canary"])
+				"""#
+			return: ["prod", "eu"]
+		},
+	]
+}
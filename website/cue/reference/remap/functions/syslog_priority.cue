@@ -0,0 +1,48 @@
+package metadata
+
+remap: functions: syslog_priority: {
+	category: "Convert"
+	description: """
+		Computes the [Syslog](\(urls.syslog)) priority (`PRI`) value from a `facility` and a
+		`severity`, as `facility * 8 + severity`. Both can be given as a code or a name. Facility
+		names are the same ones `parse_syslog` returns, so codes 13 to 15 are `audit`, `alert`,
+		and `clockd`.
+		"""
+
+	arguments: [
+		{
+			name:        "facility"
+			description: "The facility, as a code from `0` to `23` or a name such as `local0`."
+			required:    true
+			type: ["integer", "string"]
+		},
+		{
+			name:        "severity"
+			description: "The severity, as a code from `0` to `7` or a name such as `info`."
+			required:    true
+			type: ["integer", "string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`facility` is not a valid Syslog facility name or code.",
+		"`severity` is not a valid Syslog severity name or code.",
+	]
+	return: types: ["integer"]
+
+	examples: [
+		{
+			title: "Compute the priority from names"
+			source: #"""
+				syslog_priority!("local0", "info")
+				"""#
+			return: 134
+		},
+		{
+			title: "Compute the priority from codes"
+			source: #"""
+				syslog_priority!(4, 2)
+				"""#
+			return: 34
+		},
+	]
+}
@@ -0,0 +1,52 @@
+package metadata
+
+remap: functions: to_case: {
+	category: "String"
+	description: """
+		Converts the `value` string to the `target` case.
+
+		Words are split on any non-alphanumeric separator and on case boundaries. Acronyms are kept
+		together, so `HTTPRequest` becomes `http_request`, and digits stay with the word before them.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to convert."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "target"
+			description: "The case to convert the string to."
+			required:    true
+			enum: {
+				snake:           "`snake_case`"
+				camel:           "`camelCase`"
+				pascal:          "`PascalCase`"
+				kebab:           "`kebab-case`"
+				screaming_snake: "`SCREAMING_SNAKE_CASE`"
+			}
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Convert an acronym to snake case"
+			source: #"""
+				to_case("HTTPRequest", "snake")
+				"""#
+			return: "http_request"
+		},
+		{
+			title: "Convert to camel case"
+			source: #"""
+				to_case("user-agent string", "camel")
+				"""#
+			return: "userAgentString"
+		},
+	]
+}
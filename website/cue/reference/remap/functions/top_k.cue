@@ -0,0 +1,40 @@
+package metadata
+
+remap: functions: top_k: {
+	category: "Array"
+	description: """
+		Returns the `k` most frequent elements of an array as objects holding the `value` and its
+		`count`, sorted by count in descending order.
+
+		Elements with the same count are ordered by their first appearance in the array.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array to count elements of."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "k"
+			description: "The maximum number of elements to return."
+			required:    true
+			type: ["integer"]
+		},
+	]
+	internal_failure_reasons: [
+		"`k` is negative.",
+	]
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Get the two most frequent values"
+			source: #"""
+				top_k!(["a", "b", "a", "c", "b", "a"], 2)
+				"""#
+			return: [{"value": "a", "count": 3}, {"value": "b", "count": 2}]
+		},
+	]
+}
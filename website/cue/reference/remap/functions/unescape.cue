@@ -0,0 +1,58 @@
+package metadata
+
+remap: functions: unescape: {
+	category: "String"
+	description: """
+		Reverses the escaping `escape` applies to a string for the given `context`.
+
+		* `regex`: removes backslash escapes.
+		* `sql`: replaces doubled single quotes with single ones, and decodes backslash escape sequences
+		  as MySQL does, such as `\\\\` and `\\0`.
+		* `shell`: removes single quotes and backslash escapes.
+		* `json`: decodes the escape sequences of a JSON string.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to unescape."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "context"
+			description: "The context the string was escaped for."
+			required:    true
+			enum: {
+				regex: "A regular expression."
+				sql:   "A single quoted SQL string literal."
+				shell: "A POSIX shell command line."
+				json:  "A JSON string."
+			}
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` ends with a lone backslash in the `regex` or `shell` context.",
+		"`value` has an unterminated single quote in the `shell` context.",
+		"`value` is not a valid JSON string in the `json` context.",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Unescape a SQL string"
+			source: #"""
+				unescape!("O''Brien", "sql")
+				"""#
+			return: "O'Brien"
+		},
+		{
+			title: "Unescape a shell word"
+			source: #"""
+				unescape!("'it'\\''s $(rm -rf /)'", "shell")
+				"""#
+			return: "it's $(rm -rf /)"
+		},
+	]
+}
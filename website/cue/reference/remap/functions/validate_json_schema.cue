@@ -0,0 +1,68 @@
+package metadata
+
+remap: functions: validate_json_schema: {
+	category: "Type"
+	description: """
+		Validates the `value` against a [JSON Schema](\(urls.json_schema)) and returns an array of
+		validation errors. The array is empty if the `value` conforms to the schema.
+
+		Each error is prefixed with the path of the offending value, for example
+		`.user.age: expected type integer, got string`.
+
+		The following keywords are supported: `type`, `enum`, `const`, `required`, `properties`,
+		`additionalProperties`, `items`, `minItems`, `maxItems`, `minLength`, `maxLength`, `pattern`,
+		`minimum`, `maximum`, `exclusiveMinimum`, and `exclusiveMaximum`. The annotation keywords
+		`$schema`, `$id`, `$comment`, `title`, `description`, `default`, `examples`, `deprecated`,
+		`readOnly`, and `writeOnly` are accepted and ignored. Any other keyword, such as `oneOf` or
+		`$ref`, makes the schema invalid rather than being silently skipped. Timestamps and regular
+		expressions are validated as strings.
+		"""
+	notices: [
+		"""
+			If the `schema` is a literal or a path to a schema file, it is compiled once when the VRL
+			program is compiled, and an invalid or unreadable schema is reported as a compile-time
+			error. A dynamic `schema` is compiled
+			every time the function is called, which makes the function fallible.
+			""",
+	]
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The value to validate."
+			required:    true
+			type: ["any"]
+		},
+		{
+			name:        "schema"
+			description: """
+				The JSON Schema to validate against, or the path to a JSON file containing it. A
+				path must be a literal string.
+				"""
+			required:    true
+			type: ["object", "boolean", "string"]
+		},
+	]
+	internal_failure_reasons: [
+		"The dynamic `schema` is not a valid JSON Schema.",
+		"The dynamic `schema` is a string, since schema file paths must be literals.",
+	]
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Validate a conforming value"
+			source: #"""
+				validate_json_schema({"name": "alice", "age": 42}, {"type": "object", "required": ["name"], "properties": {"age": {"type": "integer"}}})
+				"""#
+			return: []
+		},
+		{
+			title: "Validate a value with a missing field and a mistyped property"
+			source: #"""
+				validate_json_schema({"age": "42"}, {"type": "object", "required": ["name"], "properties": {"age": {"type": "integer"}}})
+				"""#
+			return: [#".: missing required property "name""#, ".age: expected type integer, got string"]
+		},
+	]
+}
@@ -0,0 +1,56 @@
+package metadata
+
+remap: functions: wrap: {
+	category: "String"
+	description: """
+		Wraps the `value` into lines of at most `width` graphemes, inserting newlines at word
+		boundaries. Existing newlines are kept, and runs of whitespace between words are collapsed
+		into a single space.
+
+		A word longer than `width` is placed on its own line, unless `break_long_words` is `true`,
+		in which case it is split across lines.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to wrap."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "width"
+			description: "The maximum width of each line, in graphemes."
+			required:    true
+			type: ["integer"]
+		},
+		{
+			name:        "break_long_words"
+			description: "Whether to split words longer than `width` across lines."
+			required:    false
+			type: ["boolean"]
+			default: false
+		},
+	]
+	internal_failure_reasons: [
+		"`width` is not a positive integer.",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Wrap an alert body"
+			source: #"""
+				wrap!("disk usage on db-01 exceeded ninety percent", 16)
+				"""#
+			return: "disk usage on\ndb-01 exceeded\nninety percent"
+		},
+		{
+			title: "Break long words"
+			source: #"""
+				wrap!("id a1b2c3d4e5f6 found", 5, break_long_words: true)
+				"""#
+			return: "id\na1b2c\n3d4e5\nf6\nfound"
+		},
+	]
+}
@@ -0,0 +1,52 @@
+package metadata
+
+remap: functions: zip_object: {
+	category: "Object"
+	description: """
+		Builds an object by pairing each element of `keys` with the element of `values` at the same
+		position. If the arrays have different lengths, the extra elements of the longer one are
+		ignored. If a key appears more than once, the last value for it is kept.
+		"""
+
+	arguments: [
+		{
+			name:        "keys"
+			description: "The keys of the object. Each key must be a string."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "values"
+			description: "The values of the object."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: [
+		"An element of `keys` that is paired with a value is not a string.",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Build an object from parallel arrays"
+			source: #"""
+				zip_object!(["host", "status"], ["web-1", 200])
+				"""#
+			return: {
+				host:   "web-1"
+				status: 200
+			}
+		},
+		{
+			title: "Arrays of different lengths"
+			source: #"""
+				zip_object!(["host", "status", "env"], ["web-1", 200])
+				"""#
+			return: {
+				host:   "web-1"
+				status: 200
+			}
+		},
+	]
+}
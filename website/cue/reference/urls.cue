@@ -286,6 +286,8 @@ urls: {
 	journalctl:                                 "https://www.freedesktop.org/software/systemd/man/journalctl.html"
 	journald:                                   "https://www.freedesktop.org/software/systemd/man/systemd-journald.service.html"
 	json:                                       "\(wikipedia)/wiki/JSON"
+	json5:                                      "https://json5.org/"
+	json_schema:                                "https://json-schema.org/"
 	json_types:                                 "\(wikipedia)/wiki/JSON#Data_types_and_syntax"
 	jsonnet:                                    "https://jsonnet.org/"
 	kafka:                                      "https://kafka.apache.org/"